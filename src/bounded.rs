@@ -0,0 +1,195 @@
+use crate::{GasLimitExceeded, NearGas};
+
+/// An amount of gas that is statically known not to exceed `MAX` gas.
+///
+/// Deserializing an amount above the limit fails, so values read from configs or the network
+/// are validated at the boundary rather than rejected later by the protocol.
+///
+/// # Examples
+/// ```
+/// use near_gas::{BoundedGas, NearGas};
+///
+/// type CallbackGas = BoundedGas<{ NearGas::from_tgas(10).as_gas() }>;
+///
+/// assert!(CallbackGas::new(NearGas::from_tgas(5)).is_ok());
+/// assert!(CallbackGas::new(NearGas::from_tgas(11)).is_err());
+/// assert_eq!(CallbackGas::LIMIT, NearGas::from_tgas(10));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[repr(transparent)]
+pub struct BoundedGas<const MAX: u64> {
+    inner: NearGas,
+}
+
+/// The gas prepaid for a single function call action, limited by [`GasLimits::DEFAULT`].
+///
+/// For protocol versions with other limits, use [`GasLimits`] to validate amounts at runtime,
+/// or a [`BoundedGas`] with a different bound.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, PrepaidGas};
+///
+/// let gas = PrepaidGas::new(NearGas::from_tgas(300)).unwrap();
+/// assert_eq!(gas.get(), NearGas::from_tgas(300));
+///
+/// let err = PrepaidGas::new(NearGas::from_tgas(301)).unwrap_err();
+/// assert_eq!(err.to_string(), "gas amount 301 Tgas exceeds the limit of 300 Tgas");
+/// ```
+pub type PrepaidGas = BoundedGas<{ GasLimits::DEFAULT.max_prepaid_gas.as_gas() }>;
+
+impl<const MAX: u64> BoundedGas<MAX> {
+    /// The largest amount of gas this type can hold.
+    pub const LIMIT: NearGas = NearGas::from_gas(MAX);
+
+    /// The limit itself, as a `BoundedGas`.
+    pub const MAX: Self = Self { inner: Self::LIMIT };
+
+    /// Creates a `BoundedGas` from `gas`, failing if it exceeds the limit.
+    pub const fn new(gas: NearGas) -> Result<Self, GasLimitExceeded> {
+        if gas.as_gas() > MAX {
+            Err(GasLimitExceeded::new(gas, Self::LIMIT))
+        } else {
+            Ok(Self { inner: gas })
+        }
+    }
+
+    /// Creates a `BoundedGas` from `gas`, clamping it to the limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, PrepaidGas};
+    ///
+    /// assert_eq!(PrepaidGas::saturating_new(NearGas::from_pgas(1)), PrepaidGas::MAX);
+    /// ```
+    pub const fn saturating_new(gas: NearGas) -> Self {
+        if gas.as_gas() > MAX {
+            Self::MAX
+        } else {
+            Self { inner: gas }
+        }
+    }
+
+    /// Returns the amount as `NearGas`.
+    pub const fn get(self) -> NearGas {
+        self.inner
+    }
+
+    /// Checked addition. Computes self + rhs, returning None if the result exceeds the limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, PrepaidGas};
+    ///
+    /// let gas = PrepaidGas::new(NearGas::from_tgas(200)).unwrap();
+    /// assert!(gas.checked_add(NearGas::from_tgas(100)).is_some());
+    /// assert!(gas.checked_add(NearGas::from_tgas(101)).is_none());
+    /// ```
+    pub const fn checked_add(self, rhs: NearGas) -> Option<Self> {
+        match self.inner.checked_add(rhs) {
+            Some(gas) if gas.as_gas() <= MAX => Some(Self { inner: gas }),
+            _ => None,
+        }
+    }
+}
+
+impl<const MAX: u64> From<BoundedGas<MAX>> for NearGas {
+    fn from(gas: BoundedGas<MAX>) -> Self {
+        gas.get()
+    }
+}
+
+impl<const MAX: u64> TryFrom<NearGas> for BoundedGas<MAX> {
+    type Error = GasLimitExceeded;
+
+    fn try_from(gas: NearGas) -> Result<Self, Self::Error> {
+        Self::new(gas)
+    }
+}
+
+/// Protocol limits on the amount of gas, for validating amounts at runtime when the protocol
+/// version is only known at runtime.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasLimits, NearGas};
+///
+/// let limits = GasLimits::new(NearGas::from_pgas(1));
+/// assert!(limits.check_prepaid_gas(NearGas::from_tgas(500)).is_ok());
+/// assert!(GasLimits::DEFAULT.check_prepaid_gas(NearGas::from_tgas(500)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasLimits {
+    /// The largest amount of gas that can be prepaid for a single function call action.
+    pub max_prepaid_gas: NearGas,
+}
+
+impl GasLimits {
+    /// The limits of the NEAR protocol at the time of writing: 300 Tgas per function call.
+    pub const DEFAULT: GasLimits = GasLimits::new(NearGas::from_tgas(300));
+
+    /// Creates limits with the given maximum prepaid gas per function call.
+    pub const fn new(max_prepaid_gas: NearGas) -> Self {
+        Self { max_prepaid_gas }
+    }
+
+    /// Returns `gas` if it does not exceed the prepaid gas limit of a function call.
+    pub const fn check_prepaid_gas(&self, gas: NearGas) -> Result<NearGas, GasLimitExceeded> {
+        if gas.as_gas() > self.max_prepaid_gas.as_gas() {
+            Err(GasLimitExceeded::new(gas, self.max_prepaid_gas))
+        } else {
+            Ok(gas)
+        }
+    }
+}
+
+impl Default for GasLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BoundedGas, GasLimits, NearGas, PrepaidGas};
+
+    #[test]
+    fn prepaid_gas_limit() {
+        assert_eq!(PrepaidGas::LIMIT, NearGas::from_tgas(300));
+        assert_eq!(
+            PrepaidGas::try_from(NearGas::from_tgas(300)).map(NearGas::from),
+            Ok(NearGas::from_tgas(300))
+        );
+        let err = PrepaidGas::try_from(NearGas::from_gas(300 * 10u64.pow(12) + 1)).unwrap_err();
+        assert_eq!(err.gas(), NearGas::from_gas(300 * 10u64.pow(12) + 1));
+        assert_eq!(err.limit(), NearGas::from_tgas(300));
+        assert_eq!(PrepaidGas::default().get(), NearGas::from_gas(0));
+    }
+
+    #[test]
+    fn bounds() {
+        type Tiny = BoundedGas<10>;
+        assert_eq!(Tiny::saturating_new(NearGas::from_gas(11)), Tiny::MAX);
+        assert_eq!(Tiny::saturating_new(NearGas::from_gas(9)).get().as_gas(), 9);
+        assert_eq!(Tiny::MAX.checked_add(NearGas::from_gas(0)), Some(Tiny::MAX));
+        assert_eq!(
+            BoundedGas::<{ u64::MAX }>::MAX.checked_add(NearGas::from_gas(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn runtime_limits() {
+        let limits = GasLimits::new(NearGas::from_pgas(1));
+        assert_eq!(
+            limits.check_prepaid_gas(NearGas::from_pgas(1)),
+            Ok(NearGas::from_pgas(1))
+        );
+        assert!(
+            limits
+                .check_prepaid_gas(NearGas::from_gas(10u64.pow(15) + 1))
+                .is_err()
+        );
+        assert_eq!(GasLimits::default(), GasLimits::DEFAULT);
+    }
+}
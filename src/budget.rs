@@ -0,0 +1,356 @@
+use alloc::vec::Vec;
+
+use crate::{GasLimitExceeded, GasLimits, GasWeight, NearGas};
+
+/// A plan for splitting the gas prepaid for a function call among the call itself and the
+/// promises it creates, e.g. for `ft_transfer_call` → `ft_on_transfer` → `ft_resolve_transfer`.
+///
+/// Steps are declared in order:
+/// * [`GasBudget::reserve`] sets aside gas the function call burns itself;
+/// * [`GasBudget::attach`] attaches a fixed amount of gas to a promise;
+/// * [`GasBudget::attach_weighted`] gives a promise a share of the gas left after the reserved
+///   and fixed steps, split the way the runtime distributes unused gas, see
+///   [`NearGas::distribute`].
+///
+/// [`GasBudget::plan`] checks the prepaid gas against the protocol limits and returns a
+/// [`GasBudgetBreakdown`], which also renders as a table.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasBudget, GasWeight, NearGas};
+///
+/// let breakdown = GasBudget::new(NearGas::from_tgas(300))
+///     .reserve("ft_transfer_call", NearGas::from_tgas(10))
+///     .attach("ft_resolve_transfer", NearGas::from_tgas(20))
+///     .attach_weighted("ft_on_transfer", GasWeight(1))
+///     .plan()
+///     .unwrap();
+///
+/// assert_eq!(breakdown.get("ft_on_transfer"), Some(NearGas::from_tgas(270)));
+/// assert_eq!(breakdown.attached(), NearGas::from_tgas(290));
+/// assert_eq!(
+///     breakdown.to_string(),
+///     "\
+/// step                 kind           gas
+/// ft_transfer_call     reserved   10 Tgas
+/// ft_resolve_transfer  fixed      20 Tgas
+/// ft_on_transfer       weight 1  270 Tgas
+/// total                          300 Tgas
+/// "
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasBudget<'a> {
+    prepaid: NearGas,
+    limits: GasLimits,
+    steps: Vec<(&'a str, StepKind)>,
+}
+
+/// How a step of a [`GasBudget`] is given gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum StepKind {
+    Reserved(NearGas),
+    Fixed(NearGas),
+    Weighted(GasWeight),
+}
+
+impl<'a> GasBudget<'a> {
+    /// Creates an empty plan for `prepaid` gas, checked against [`GasLimits::DEFAULT`].
+    pub fn new(prepaid: NearGas) -> Self {
+        Self {
+            prepaid,
+            limits: GasLimits::DEFAULT,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Sets the protocol limits the prepaid gas is checked against.
+    pub fn limits(mut self, limits: GasLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets aside `gas` for the function call itself, e.g. for the work it does before and
+    /// after creating promises.
+    pub fn reserve(mut self, name: &'a str, gas: NearGas) -> Self {
+        self.steps.push((name, StepKind::Reserved(gas)));
+        self
+    }
+
+    /// Attaches a fixed amount of `gas` to a promise.
+    pub fn attach(mut self, name: &'a str, gas: NearGas) -> Self {
+        self.steps.push((name, StepKind::Fixed(gas)));
+        self
+    }
+
+    /// Gives a promise a share of the gas left after all reserved and fixed steps, proportional
+    /// to `weight`.
+    pub fn attach_weighted(mut self, name: &'a str, weight: GasWeight) -> Self {
+        self.steps.push((name, StepKind::Weighted(weight)));
+        self
+    }
+
+    /// Computes the gas of every step.
+    ///
+    /// Fails if the prepaid gas exceeds the limits, or if the reserved and fixed steps need more
+    /// gas than is prepaid. Gas left over when there are no weighted steps, or all of them have
+    /// weight 0, is reported as [`GasBudgetBreakdown::unallocated`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasBudget, GasBudgetError, NearGas};
+    ///
+    /// let err = GasBudget::new(NearGas::from_tgas(30))
+    ///     .reserve("ft_transfer_call", NearGas::from_tgas(10))
+    ///     .attach("ft_on_transfer", NearGas::from_tgas(25))
+    ///     .plan()
+    ///     .unwrap_err();
+    /// assert!(matches!(err, GasBudgetError::Overcommitted { step: "ft_on_transfer", .. }));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "step `ft_on_transfer` needs 25 Tgas, but only 20 Tgas of 30 Tgas prepaid gas are left"
+    /// );
+    /// ```
+    pub fn plan(&self) -> Result<GasBudgetBreakdown<'a>, GasBudgetError<'a>> {
+        self.limits
+            .check_prepaid_gas(self.prepaid)
+            .map_err(GasBudgetError::PrepaidLimitExceeded)?;
+
+        let mut remaining = self.prepaid;
+        let mut allocations = Vec::with_capacity(self.steps.len());
+        let mut weights = Vec::new();
+        for &(name, kind) in &self.steps {
+            let (kind, gas) = match kind {
+                StepKind::Reserved(gas) => (AllocationKind::Reserved, gas),
+                StepKind::Fixed(gas) => (AllocationKind::Fixed, gas),
+                StepKind::Weighted(weight) => {
+                    weights.push(weight);
+                    (AllocationKind::Weighted(weight), NearGas::from_gas(0))
+                }
+            };
+            remaining = remaining
+                .checked_sub(gas)
+                .ok_or(GasBudgetError::Overcommitted {
+                    step: name,
+                    required: gas,
+                    available: remaining,
+                    prepaid: self.prepaid,
+                })?;
+            allocations.push(GasAllocation { name, kind, gas });
+        }
+
+        let shares = remaining.distribute(&weights);
+        let weighted = allocations
+            .iter_mut()
+            .filter(|allocation| matches!(allocation.kind, AllocationKind::Weighted(_)));
+        for (allocation, share) in weighted.zip(shares) {
+            allocation.gas = share;
+            remaining = remaining.saturating_sub(share);
+        }
+
+        Ok(GasBudgetBreakdown {
+            prepaid: self.prepaid,
+            allocations,
+            unallocated: remaining,
+        })
+    }
+}
+
+/// The gas computed for every step of a [`GasBudget`].
+///
+/// `Display` renders a table with a row per step, the unallocated gas, if any, and the total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasBudgetBreakdown<'a> {
+    /// The prepaid gas the plan was made for.
+    pub prepaid: NearGas,
+    /// The gas of every step, in the order the steps were declared.
+    pub allocations: Vec<GasAllocation<'a>>,
+    /// The prepaid gas not given to any step.
+    pub unallocated: NearGas,
+}
+
+impl GasBudgetBreakdown<'_> {
+    /// Returns the gas of the first step named `name`.
+    pub fn get(&self, name: &str) -> Option<NearGas> {
+        self.allocations
+            .iter()
+            .find(|allocation| allocation.name == name)
+            .map(|allocation| allocation.gas)
+    }
+
+    /// Returns the total gas reserved for the function call itself.
+    pub fn reserved(&self) -> NearGas {
+        self.sum(|kind| matches!(kind, AllocationKind::Reserved))
+    }
+
+    /// Returns the total gas attached to promises, both fixed and weighted.
+    pub fn attached(&self) -> NearGas {
+        self.sum(|kind| !matches!(kind, AllocationKind::Reserved))
+    }
+
+    fn sum(&self, filter: impl Fn(AllocationKind) -> bool) -> NearGas {
+        // Cannot overflow, as the allocations never exceed the prepaid gas.
+        self.allocations
+            .iter()
+            .filter(|allocation| filter(allocation.kind))
+            .fold(NearGas::from_gas(0), |sum, allocation| {
+                sum.saturating_add(allocation.gas)
+            })
+    }
+}
+
+/// The gas given to a single step of a [`GasBudget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasAllocation<'a> {
+    /// The name of the step.
+    pub name: &'a str,
+    /// How the step was given gas.
+    pub kind: AllocationKind,
+    /// The gas of the step.
+    pub gas: NearGas,
+}
+
+/// How a step of a [`GasBudget`] was given gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllocationKind {
+    /// Gas set aside for the function call itself with [`GasBudget::reserve`].
+    Reserved,
+    /// A fixed amount attached with [`GasBudget::attach`].
+    Fixed,
+    /// A share of the remaining gas attached with [`GasBudget::attach_weighted`].
+    Weighted(GasWeight),
+}
+
+/// Error returned by [`GasBudget::plan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GasBudgetError<'a> {
+    /// The prepaid gas exceeds the protocol limits.
+    PrepaidLimitExceeded(GasLimitExceeded),
+    /// A reserved or fixed step needs more gas than is left after the previous steps.
+    Overcommitted {
+        /// The name of the step.
+        step: &'a str,
+        /// The gas the step needs.
+        required: NearGas,
+        /// The gas left after the previous steps.
+        available: NearGas,
+        /// The prepaid gas the plan was made for.
+        prepaid: NearGas,
+    },
+}
+
+impl core::error::Error for GasBudgetError<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            GasBudgetError::PrepaidLimitExceeded(err) => Some(err),
+            GasBudgetError::Overcommitted { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AllocationKind, GasAllocation, GasBudget, GasBudgetError, GasLimits, GasWeight, NearGas,
+    };
+
+    #[test]
+    fn weighted_steps_share_the_remainder() {
+        let breakdown = GasBudget::new(NearGas::from_tgas(100))
+            .attach_weighted("a", GasWeight(1))
+            .reserve("self", NearGas::from_tgas(10))
+            .attach_weighted("b", GasWeight(2))
+            .attach("c", NearGas::from_tgas(30))
+            .attach_weighted("d", GasWeight(0))
+            .plan()
+            .unwrap();
+        assert_eq!(
+            breakdown.allocations,
+            [
+                GasAllocation {
+                    name: "a",
+                    kind: AllocationKind::Weighted(GasWeight(1)),
+                    gas: NearGas::from_gas(20_000_000_000_000),
+                },
+                GasAllocation {
+                    name: "self",
+                    kind: AllocationKind::Reserved,
+                    gas: NearGas::from_tgas(10),
+                },
+                GasAllocation {
+                    name: "b",
+                    kind: AllocationKind::Weighted(GasWeight(2)),
+                    gas: NearGas::from_tgas(40),
+                },
+                GasAllocation {
+                    name: "c",
+                    kind: AllocationKind::Fixed,
+                    gas: NearGas::from_tgas(30),
+                },
+                GasAllocation {
+                    name: "d",
+                    kind: AllocationKind::Weighted(GasWeight(0)),
+                    gas: NearGas::from_gas(0),
+                },
+            ]
+        );
+        assert_eq!(breakdown.unallocated, NearGas::from_gas(0));
+        assert_eq!(breakdown.reserved(), NearGas::from_tgas(10));
+        assert_eq!(breakdown.attached(), NearGas::from_tgas(90));
+    }
+
+    #[test]
+    fn leftover_is_unallocated() {
+        let breakdown = GasBudget::new(NearGas::from_tgas(50))
+            .reserve("self", NearGas::from_tgas(5))
+            .attach("callback", NearGas::from_tgas(15))
+            .plan()
+            .unwrap();
+        assert_eq!(breakdown.unallocated, NearGas::from_tgas(30));
+        assert_eq!(breakdown.get("callback"), Some(NearGas::from_tgas(15)));
+        assert_eq!(breakdown.get("missing"), None);
+        assert_eq!(
+            breakdown.to_string(),
+            "\
+step         kind          gas
+self         reserved   5 Tgas
+callback     fixed     15 Tgas
+unallocated            30 Tgas
+total                  50 Tgas
+"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let budget = GasBudget::new(NearGas::from_tgas(500)).reserve("self", NearGas::from_tgas(5));
+        let err = budget.plan().unwrap_err();
+        assert!(matches!(err, GasBudgetError::PrepaidLimitExceeded(_)));
+        assert_eq!(
+            err.to_string(),
+            "prepaid gas exceeds the protocol limit: \
+             gas amount 500 Tgas exceeds the limit of 300 Tgas"
+        );
+        assert!(
+            budget
+                .limits(GasLimits::new(NearGas::from_pgas(1)))
+                .plan()
+                .is_ok()
+        );
+
+        assert_eq!(
+            GasBudget::new(NearGas::from_tgas(10))
+                .attach("a", NearGas::from_tgas(6))
+                .attach_weighted("b", GasWeight(1))
+                .reserve("c", NearGas::from_tgas(5))
+                .plan(),
+            Err(GasBudgetError::Overcommitted {
+                step: "c",
+                required: NearGas::from_tgas(5),
+                available: NearGas::from_tgas(4),
+                prepaid: NearGas::from_tgas(10),
+            })
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use crate::NearGas;
+
+/// A signed difference between two amounts of gas, e.g. between an estimate and the actual burn.
+///
+/// Any difference of two `NearGas` values can be represented, so the magnitude of a delta never
+/// exceeds `u64::MAX` gas.
+///
+/// `Display` renders the sign followed by the magnitude formatted like `NearGas`, and `FromStr`
+/// accepts the same notation, with the `+` sign being optional.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, NearGasDelta};
+///
+/// let estimate = NearGas::from_tgas(5);
+/// let burnt = NearGas::from_ggas(5_400);
+/// let delta = burnt.signed_diff(estimate);
+/// assert_eq!(delta.to_string(), "+0.4 Tgas");
+/// assert_eq!(estimate.signed_diff(burnt).to_string(), "-0.4 Tgas");
+/// assert_eq!(estimate.checked_add_signed(delta), Some(burnt));
+/// assert_eq!("-400 Ggas".parse(), Ok(estimate.signed_diff(burnt)));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+pub struct NearGasDelta {
+    /// Always within `-(u64::MAX as i128)..=u64::MAX as i128`.
+    inner: i128,
+}
+
+impl NearGasDelta {
+    /// The largest representable delta, `u64::MAX` gas.
+    pub const MAX: NearGasDelta = NearGasDelta::positive(NearGas::from_gas(u64::MAX));
+
+    /// The smallest representable delta, `-u64::MAX` gas.
+    pub const MIN: NearGasDelta = NearGasDelta::negative(NearGas::from_gas(u64::MAX));
+
+    /// Creates a delta that increases an amount by `gas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// assert_eq!(NearGasDelta::positive(NearGas::from_gas(5)).as_gas(), 5);
+    /// ```
+    pub const fn positive(gas: NearGas) -> Self {
+        Self {
+            inner: gas.as_gas() as i128,
+        }
+    }
+
+    /// Creates a delta that decreases an amount by `gas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// assert_eq!(NearGasDelta::negative(NearGas::from_gas(5)).as_gas(), -5);
+    /// ```
+    pub const fn negative(gas: NearGas) -> Self {
+        Self {
+            inner: -(gas.as_gas() as i128),
+        }
+    }
+
+    /// Creates a delta from a signed number of gas, returning None if its magnitude exceeds
+    /// `u64::MAX` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGasDelta;
+    ///
+    /// assert_eq!(NearGasDelta::checked_from_gas(-5).map(NearGasDelta::as_gas), Some(-5));
+    /// assert_eq!(NearGasDelta::checked_from_gas(i128::MAX), None);
+    /// ```
+    pub const fn checked_from_gas(gas: i128) -> Option<Self> {
+        if gas.unsigned_abs() > u64::MAX as u128 {
+            None
+        } else {
+            Some(Self { inner: gas })
+        }
+    }
+
+    /// Returns the signed number of gas.
+    pub const fn as_gas(self) -> i128 {
+        self.inner
+    }
+
+    /// Returns the absolute value of the delta.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// let delta = NearGasDelta::negative(NearGas::from_tgas(1));
+    /// assert_eq!(delta.magnitude(), NearGas::from_tgas(1));
+    /// ```
+    pub const fn magnitude(self) -> NearGas {
+        NearGas::from_gas(self.inner.unsigned_abs() as u64)
+    }
+
+    /// Returns whether the delta is zero.
+    pub const fn is_zero(self) -> bool {
+        self.inner == 0
+    }
+
+    /// Returns whether the delta is greater than zero.
+    pub const fn is_positive(self) -> bool {
+        self.inner > 0
+    }
+
+    /// Returns whether the delta is less than zero.
+    pub const fn is_negative(self) -> bool {
+        self.inner < 0
+    }
+
+    /// Checked addition. Computes self + rhs, returning None if the magnitude of the result
+    /// exceeds `u64::MAX` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// let plus_one = NearGasDelta::positive(NearGas::from_gas(1));
+    /// let minus_two = NearGasDelta::negative(NearGas::from_gas(2));
+    /// assert_eq!(plus_one.checked_add(minus_two), NearGasDelta::checked_from_gas(-1));
+    /// assert_eq!(NearGasDelta::MAX.checked_add(plus_one), None);
+    /// ```
+    pub const fn checked_add(self, rhs: NearGasDelta) -> Option<Self> {
+        Self::checked_from_gas(self.inner + rhs.inner)
+    }
+
+    /// Checked subtraction. Computes self - rhs, returning None if the magnitude of the result
+    /// exceeds `u64::MAX` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// let plus_one = NearGasDelta::positive(NearGas::from_gas(1));
+    /// assert_eq!(plus_one.checked_sub(plus_one), Some(NearGasDelta::default()));
+    /// assert_eq!(NearGasDelta::MIN.checked_sub(plus_one), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: NearGasDelta) -> Option<Self> {
+        Self::checked_from_gas(self.inner - rhs.inner)
+    }
+}
+
+impl From<NearGas> for NearGasDelta {
+    fn from(gas: NearGas) -> Self {
+        Self::positive(gas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearGas, NearGasDelta};
+
+    #[test]
+    fn signed_diff_covers_full_range() {
+        let max = NearGas::from_gas(u64::MAX);
+        let zero = NearGas::from_gas(0);
+        assert_eq!(max.signed_diff(zero), NearGasDelta::MAX);
+        assert_eq!(zero.signed_diff(max), NearGasDelta::MIN);
+        assert_eq!(zero.checked_add_signed(NearGasDelta::MAX), Some(max));
+        assert_eq!(max.checked_add_signed(NearGasDelta::MIN), Some(zero));
+        assert_eq!(
+            max.checked_add_signed(NearGasDelta::positive(NearGas::from_gas(1))),
+            None
+        );
+        assert_eq!(NearGasDelta::MIN.magnitude(), max);
+    }
+
+    #[test]
+    fn checked_from_gas() {
+        assert_eq!(
+            NearGasDelta::checked_from_gas(u64::MAX as i128),
+            Some(NearGasDelta::MAX)
+        );
+        assert_eq!(
+            NearGasDelta::checked_from_gas(-(u64::MAX as i128)),
+            Some(NearGasDelta::MIN)
+        );
+        assert_eq!(NearGasDelta::checked_from_gas(u64::MAX as i128 + 1), None);
+        assert_eq!(NearGasDelta::checked_from_gas(i128::MIN), None);
+    }
+
+    #[test]
+    fn sign() {
+        let minus = NearGasDelta::negative(NearGas::from_gas(1));
+        assert!(minus.is_negative() && !minus.is_positive() && !minus.is_zero());
+        assert!(NearGasDelta::default().is_zero());
+        assert!(NearGasDelta::negative(NearGas::from_gas(0)).is_zero());
+        assert!(minus < NearGasDelta::default());
+    }
+}
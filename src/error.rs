@@ -1,14 +1,259 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum NearGasError {
-    IncorrectNumber(crate::utils::DecimalNumberParsingError),
-    IncorrectUnit(String),
+use crate::{GasUnit, NearGas};
+
+/// The reason a string could not be parsed into `NearGas` or `GasUnit`.
+///
+/// Kinds that only arithmetic expressions can produce exist only with the `expr` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NearGasErrorKind {
+    /// The input is empty or consists of whitespace only.
+    Empty,
+    /// There is no number in front of the unit.
+    MissingNumber,
+    /// The number is not followed by a unit.
+    MissingUnit,
+    /// The unit is not one of the known gas units.
+    UnknownUnit,
+    /// The number contains a character that is not a decimal digit where one is expected.
+    InvalidDigit,
+    /// The number has more fractional digits than the unit allows, so it is not a whole amount of gas.
+    TooManyFractionalDigits,
+    /// The amount of gas does not fit into `u64`.
+    Overflow,
+    /// An arithmetic expression or a [`crate::GasPrice`] contains a character that cannot appear
+    /// at this position.
+    UnexpectedToken,
+    /// An operator or a parenthesis is not preceded or followed by a value, e.g. `5 Tgas +`.
+    #[cfg(feature = "expr")]
+    MissingOperand,
+    /// An opening parenthesis has no matching closing parenthesis.
+    #[cfg(feature = "expr")]
+    UnclosedParenthesis,
+    /// Parentheses are nested too deeply.
+    #[cfg(feature = "expr")]
+    TooDeeplyNested,
+    /// An operator is applied to operands it does not support, e.g. adding a plain number to
+    /// an amount of gas, or multiplying two amounts of gas.
+    #[cfg(feature = "expr")]
+    InvalidOperands,
+    /// A subtraction results in a negative amount.
+    #[cfg(feature = "expr")]
+    Underflow,
+    /// A division by zero.
+    #[cfg(feature = "expr")]
+    DivisionByZero,
+    /// The input is valid, but not in the canonical notation required by [`crate::ParseMode::Strict`].
+    NonCanonical,
+    /// The amount of gas is zero where a non-zero amount is required.
+    Zero,
+    /// The currency of a [`crate::GasPrice`] is not one of `NEAR`, `milliNEAR` or `yoctoNEAR`.
+    UnknownCurrency,
 }
 
-impl std::error::Error for NearGasError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl NearGasErrorKind {
+    /// Returns a short description of the error, as used by `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGasErrorKind;
+    /// assert_eq!(NearGasErrorKind::MissingUnit.description(), "missing unit");
+    /// ```
+    pub const fn description(self) -> &'static str {
         match self {
-            NearGasError::IncorrectNumber(err) => Some(err),
-            NearGasError::IncorrectUnit(_) => None,
+            NearGasErrorKind::Empty => "empty input",
+            NearGasErrorKind::MissingNumber => "missing number before the unit",
+            NearGasErrorKind::MissingUnit => "missing unit",
+            NearGasErrorKind::UnknownUnit => "unknown unit",
+            NearGasErrorKind::InvalidDigit => "invalid digit",
+            NearGasErrorKind::TooManyFractionalDigits => "too many fractional digits",
+            NearGasErrorKind::Overflow => "number is too large",
+            NearGasErrorKind::UnexpectedToken => "unexpected token",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::MissingOperand => "missing operand",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::UnclosedParenthesis => "unclosed parenthesis",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::TooDeeplyNested => "parentheses are nested too deeply",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::InvalidOperands => "operator cannot be applied to these operands",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::Underflow => "result is negative",
+            #[cfg(feature = "expr")]
+            NearGasErrorKind::DivisionByZero => "division by zero",
+            NearGasErrorKind::NonCanonical => "not in canonical notation",
+            NearGasErrorKind::Zero => "amount must not be zero",
+            NearGasErrorKind::UnknownCurrency => {
+                "unknown currency, expected one of: NEAR, milliNEAR, yoctoNEAR"
+            }
+        }
+    }
+}
+
+/// Error returned when parsing a string into `NearGas` or `GasUnit` fails.
+///
+/// The error does not own a copy of the input: it records the kind of the problem and the byte
+/// range of the offending token within the parsed string, which makes it cheap to create and
+/// usable without an allocator.
+///
+/// As a consequence, `Display` refers to the offending token by its byte range only. Call
+/// [`NearGasError::with_input`] with the parsed string to get a message that quotes the input
+/// and the offending token.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasUnit, NearGas, NearGasErrorKind};
+///
+/// let err = "1.5 Tgs".parse::<NearGas>().unwrap_err();
+/// assert_eq!(err.kind(), NearGasErrorKind::UnknownUnit);
+/// assert_eq!(err.span(), 4..7);
+/// assert_eq!(err.suggestion(), Some(GasUnit::Tgas));
+/// assert_eq!(
+///     err.to_string(),
+///     "unknown unit at bytes 4..7, did you mean `Tgas`? Expected one of: gas, Kgas, Mgas, Ggas, Tgas, Pgas"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NearGasError {
+    kind: NearGasErrorKind,
+    start: usize,
+    end: usize,
+    unit: Option<GasUnit>,
+    suggestion: Option<GasUnit>,
+}
+
+impl NearGasError {
+    pub(crate) const fn new(kind: NearGasErrorKind, start: usize, end: usize) -> Self {
+        Self {
+            kind,
+            start,
+            end,
+            unit: None,
+            suggestion: None,
         }
     }
+
+    /// Shifts the span of the error by `offset` bytes, used when the error was produced while
+    /// parsing a substring of the input.
+    pub(crate) const fn offset(mut self, offset: usize) -> Self {
+        self.start += offset;
+        self.end += offset;
+        self
+    }
+
+    /// Records the unit the number was parsed in.
+    pub(crate) const fn with_unit(mut self, unit: GasUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Records the unit the unknown unit was most likely meant to be.
+    pub(crate) const fn with_suggestion(mut self, suggestion: Option<GasUnit>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    /// Returns the kind of the error.
+    pub const fn kind(&self) -> NearGasErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the offending token within the parsed string.
+    ///
+    /// The range is empty when something is missing, in which case it points at the position
+    /// where it was expected.
+    pub const fn span(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the unit the number was being parsed in, if it was known when the error occurred.
+    pub const fn unit(&self) -> Option<GasUnit> {
+        self.unit
+    }
+
+    /// Returns the known unit that an unknown unit most closely resembles, e.g. `Tgas` for `Tgs`.
+    pub const fn suggestion(&self) -> Option<GasUnit> {
+        self.suggestion
+    }
+
+    /// Pairs the error with the string that failed to parse, so it can be displayed with the
+    /// original input and the offending token quoted.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let input = "1.5 Tgs";
+    /// let err = input.parse::<NearGas>().unwrap_err();
+    /// assert_eq!(
+    ///     err.with_input(input).to_string(),
+    ///     "invalid gas amount \"1.5 Tgs\": unknown unit \"Tgs\" at bytes 4..7, did you mean `Tgas`? \
+    ///      Expected one of: gas, Kgas, Mgas, Ggas, Tgas, Pgas"
+    /// );
+    /// ```
+    pub const fn with_input(self, input: &str) -> NearGasErrorReport<'_> {
+        NearGasErrorReport { error: self, input }
+    }
 }
+
+impl core::error::Error for NearGasError {}
+
+/// A [`NearGasError`] together with the input that failed to parse, created by [`NearGasError::with_input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NearGasErrorReport<'a> {
+    pub(crate) error: NearGasError,
+    pub(crate) input: &'a str,
+}
+
+impl NearGasErrorReport<'_> {
+    /// Returns the underlying error.
+    pub const fn error(&self) -> NearGasError {
+        self.error
+    }
+}
+
+impl core::error::Error for NearGasErrorReport<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Error returned when an amount of gas exceeds a limit, e.g. the prepaid gas limit of
+/// [`crate::PrepaidGas`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasLimitExceeded {
+    gas: NearGas,
+    limit: NearGas,
+}
+
+impl GasLimitExceeded {
+    pub(crate) const fn new(gas: NearGas, limit: NearGas) -> Self {
+        Self { gas, limit }
+    }
+
+    /// Returns the amount of gas that exceeds the limit.
+    pub const fn gas(&self) -> NearGas {
+        self.gas
+    }
+
+    /// Returns the limit that was exceeded.
+    pub const fn limit(&self) -> NearGas {
+        self.limit
+    }
+}
+
+impl core::error::Error for GasLimitExceeded {}
+
+/// Error returned when converting a floating point amount of gas units into `NearGas`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatConversionError {
+    /// The value is NaN.
+    NaN,
+    /// The value is positive or negative infinity.
+    Infinite,
+    /// The value is negative.
+    Negative,
+    /// The value exceeds `u64::MAX` gas.
+    Overflow,
+}
+
+impl core::error::Error for FloatConversionError {}
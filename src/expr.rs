@@ -0,0 +1,305 @@
+use crate::parse::parse_near_gas;
+use crate::utils::parse_decimal_number;
+use crate::{NearGas, NearGasError, NearGasErrorKind, ParseOptions};
+
+/// Parentheses nested deeper than this are rejected instead of risking a stack overflow.
+const MAX_DEPTH: usize = 32;
+
+/// An evaluated subexpression together with the byte range it was parsed from.
+#[derive(Clone, Copy)]
+struct Operand {
+    value: Value,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Value {
+    Gas(u64),
+    Scalar(u64),
+}
+
+/// A recursive descent parser for
+///
+/// ```text
+/// expr   = term { ("+" | "-") term }
+/// term   = factor { ("*" | "/") factor }
+/// factor = "(" expr ")" | quantity | integer
+/// ```
+///
+/// where a quantity is anything `FromStr for NearGas` accepts, e.g. `1.5 Tgas`.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+pub(crate) fn parse_expr(s: &str) -> Result<NearGas, NearGasError> {
+    if s.trim().is_empty() {
+        return Err(NearGasError::new(NearGasErrorKind::Empty, 0, s.len()));
+    }
+    let mut parser = Parser {
+        s,
+        pos: 0,
+        depth: 0,
+    };
+    let operand = parser.expr()?;
+    parser.skip_whitespace();
+    if parser.pos < s.len() {
+        return Err(parser.unexpected_token());
+    }
+    match operand.value {
+        Value::Gas(gas) => Ok(NearGas::from_gas(gas)),
+        Value::Scalar(_) => Err(NearGasError::new(
+            NearGasErrorKind::MissingUnit,
+            operand.end,
+            operand.end,
+        )),
+    }
+}
+
+impl Parser<'_> {
+    fn expr(&mut self) -> Result<Operand, NearGasError> {
+        let mut lhs = self.term()?;
+        while let Some(op @ (b'+' | b'-')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = binary(op, lhs, rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Operand, NearGasError> {
+        let mut lhs = self.factor()?;
+        while let Some(op @ (b'*' | b'/')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.factor()?;
+            lhs = binary(op, lhs, rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    fn factor(&mut self) -> Result<Operand, NearGasError> {
+        match self.peek() {
+            Some(b'(') => {
+                let open = self.pos;
+                if self.depth == MAX_DEPTH {
+                    return Err(NearGasError::new(
+                        NearGasErrorKind::TooDeeplyNested,
+                        open,
+                        open + 1,
+                    ));
+                }
+                self.pos += 1;
+                self.depth += 1;
+                let inner = self.expr()?;
+                self.depth -= 1;
+                if self.peek() != Some(b')') {
+                    return Err(match self.peek() {
+                        None => {
+                            NearGasError::new(NearGasErrorKind::UnclosedParenthesis, open, open + 1)
+                        }
+                        Some(_) => self.unexpected_token(),
+                    });
+                }
+                self.pos += 1;
+                Ok(Operand {
+                    value: inner.value,
+                    start: open,
+                    end: self.pos,
+                })
+            }
+            Some(b) if b.is_ascii_alphanumeric() || b == b'.' => self.quantity(),
+            Some(b'+' | b'-' | b'*' | b'/' | b')') | None => Err(NearGasError::new(
+                NearGasErrorKind::MissingOperand,
+                self.pos,
+                self.pos,
+            )),
+            Some(_) => Err(self.unexpected_token()),
+        }
+    }
+
+    /// Parses a number, optionally followed by a unit.
+    fn quantity(&mut self) -> Result<Operand, NearGasError> {
+        let bytes = self.s.as_bytes();
+        let start = self.pos;
+        let mut end = start;
+        while end < bytes.len()
+            && (bytes[end].is_ascii_digit() || matches!(bytes[end], b'.' | b'_'))
+        {
+            end += 1;
+        }
+        let number_end = end;
+        while end < bytes.len() && bytes[end].is_ascii_whitespace() {
+            end += 1;
+        }
+        let unit_start = end;
+        while end < bytes.len() && bytes[end].is_ascii_alphabetic() {
+            end += 1;
+        }
+
+        let value = if end > unit_start {
+            let gas = parse_near_gas(&self.s[start..end], ParseOptions::default())
+                .map_err(|err| err.offset(start))?;
+            Value::Gas(gas.as_gas())
+        } else {
+            end = number_end;
+            let scalar =
+                parse_decimal_number(&self.s.as_bytes()[start..end], 1, ParseOptions::default())
+                    .map_err(|err| err.offset(start))?;
+            Value::Scalar(scalar)
+        };
+        self.pos = end;
+        Ok(Operand { value, start, end })
+    }
+
+    /// Skips whitespace and returns the next byte, if any.
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.s.len() - self.s[self.pos..].trim_start().len();
+    }
+
+    fn unexpected_token(&self) -> NearGasError {
+        let len = self.s[self.pos..].chars().next().map_or(0, char::len_utf8);
+        NearGasError::new(NearGasErrorKind::UnexpectedToken, self.pos, self.pos + len)
+    }
+}
+
+fn binary(op: u8, lhs: Operand, rhs: Operand) -> Result<Operand, NearGasError> {
+    use NearGasErrorKind::{DivisionByZero, InvalidOperands, Overflow, Underflow};
+    use Value::{Gas, Scalar};
+
+    let value = match (op, lhs.value, rhs.value) {
+        (b'+', Gas(a), Gas(b)) => a.checked_add(b).map(Gas).ok_or(Overflow),
+        (b'+', Scalar(a), Scalar(b)) => a.checked_add(b).map(Scalar).ok_or(Overflow),
+        (b'-', Gas(a), Gas(b)) => a.checked_sub(b).map(Gas).ok_or(Underflow),
+        (b'-', Scalar(a), Scalar(b)) => a.checked_sub(b).map(Scalar).ok_or(Underflow),
+        (b'*', Gas(a), Scalar(b)) | (b'*', Scalar(a), Gas(b)) => {
+            a.checked_mul(b).map(Gas).ok_or(Overflow)
+        }
+        (b'*', Scalar(a), Scalar(b)) => a.checked_mul(b).map(Scalar).ok_or(Overflow),
+        (b'/', Gas(a), Scalar(b)) => a.checked_div(b).map(Gas).ok_or(DivisionByZero),
+        (b'/', Scalar(a), Scalar(b)) => a.checked_div(b).map(Scalar).ok_or(DivisionByZero),
+        _ => Err(InvalidOperands),
+    };
+    match value {
+        Ok(value) => Ok(Operand {
+            value,
+            start: lhs.start,
+            end: rhs.end,
+        }),
+        Err(kind) => Err(NearGasError::new(kind, lhs.start, rhs.end)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasUnit, NearGas, NearGasError, NearGasErrorKind};
+
+    #[test]
+    fn evaluates_expressions() {
+        for (input, expected) in [
+            ("5 Tgas", NearGas::from_tgas(5)),
+            ("5 Tgas + 300 Ggas", NearGas::from_ggas(5_300)),
+            ("3 * 10 Tgas", NearGas::from_tgas(30)),
+            ("10 Tgas * 3", NearGas::from_tgas(30)),
+            ("10 Tgas / 4", NearGas::from_ggas(2_500)),
+            ("1 Tgas - 1 Ggas - 1 Mgas", NearGas::from_mgas(998_999)),
+            ("2 * 3 Tgas + 1 Tgas", NearGas::from_tgas(7)),
+            ("2 * (3 Tgas + 1 Tgas)", NearGas::from_tgas(8)),
+            ("(1 + 2) * 1.5 Tgas", NearGas::from_ggas(4_500)),
+            ("((1Tgas))", NearGas::from_tgas(1)),
+            ("  7 gas/2  ", NearGas::from_gas(3)),
+        ] {
+            assert_eq!(NearGas::parse_expr(input), Ok(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn reports_positioned_errors() {
+        for (input, expected) in [
+            ("", NearGasError::new(NearGasErrorKind::Empty, 0, 0)),
+            ("3", NearGasError::new(NearGasErrorKind::MissingUnit, 1, 1)),
+            (
+                "5 Tgas +",
+                NearGasError::new(NearGasErrorKind::MissingOperand, 8, 8),
+            ),
+            (
+                "- 5 Tgas",
+                NearGasError::new(NearGasErrorKind::MissingOperand, 0, 0),
+            ),
+            (
+                "(5 Tgas",
+                NearGasError::new(NearGasErrorKind::UnclosedParenthesis, 0, 1),
+            ),
+            (
+                "5 Tgas)",
+                NearGasError::new(NearGasErrorKind::UnexpectedToken, 6, 7),
+            ),
+            (
+                "5 Tgas % 2",
+                NearGasError::new(NearGasErrorKind::UnexpectedToken, 7, 8),
+            ),
+            (
+                "5 Tgas + 3",
+                NearGasError::new(NearGasErrorKind::InvalidOperands, 0, 10),
+            ),
+            (
+                "1 + 2 Tgas * 2 Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidOperands, 4, 19),
+            ),
+            (
+                "1 Tgas / 0",
+                NearGasError::new(NearGasErrorKind::DivisionByZero, 0, 10),
+            ),
+            (
+                "1 Tgas + (1 Ggas - 2 Ggas)",
+                NearGasError::new(NearGasErrorKind::Underflow, 10, 25),
+            ),
+            (
+                "10000 Pgas + 10000 Pgas",
+                NearGasError::new(NearGasErrorKind::Overflow, 0, 23),
+            ),
+            (
+                "1 + 5 Tgs",
+                NearGasError::new(NearGasErrorKind::UnknownUnit, 6, 9)
+                    .with_suggestion(Some(GasUnit::Tgas)),
+            ),
+            (
+                "2 * 1.5",
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 6, 7),
+            ),
+        ] {
+            assert_eq!(NearGas::parse_expr(input), Err(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn limits_nesting() {
+        let nested = format!("{}1 Tgas{}", "(".repeat(32), ")".repeat(32));
+        assert_eq!(NearGas::parse_expr(&nested), Ok(NearGas::from_tgas(1)));
+
+        let too_nested = format!("{}1 Tgas{}", "(".repeat(33), ")".repeat(33));
+        assert_eq!(
+            NearGas::parse_expr(&too_nested),
+            Err(NearGasError::new(NearGasErrorKind::TooDeeplyNested, 32, 33))
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let input = "1 Tgas + (1 Ggas - 2 Ggas)";
+        assert_eq!(
+            NearGas::parse_expr(input)
+                .unwrap_err()
+                .with_input(input)
+                .to_string(),
+            "invalid gas amount \"1 Tgas + (1 Ggas - 2 Ggas)\": \
+             result is negative \"1 Ggas - 2 Ggas\" at bytes 10..25"
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use crate::utils::write_padded;
+use crate::{GasUnit, NearGas, RoundingMode};
+
+/// Rounding strategy used by [`NearGasDisplay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DisplayRounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero if any digit is cut off.
+    Up,
+    /// Round to the nearest value, ties are rounded away from zero.
+    Nearest,
+    /// Never round: print as many fractional digits as required to represent the value exactly,
+    /// but at least as many as requested by [`NearGasDisplay::precision`].
+    Exact,
+}
+
+impl From<RoundingMode> for DisplayRounding {
+    fn from(mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::Down => DisplayRounding::Down,
+            RoundingMode::Up => DisplayRounding::Up,
+            RoundingMode::Nearest => DisplayRounding::Nearest,
+        }
+    }
+}
+
+/// How the unit is rendered by [`NearGasDisplay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitStyle {
+    /// The canonical short name, e.g. `Tgas`.
+    Short,
+    /// The spelled out name, e.g. `teragas`.
+    Long,
+}
+
+/// A configurable formatter for `NearGas`, created by [`NearGas::display`].
+///
+/// By default the value is rendered in Tgas, with one fractional digit, rounded up, which matches
+/// the `Display` implementation of `NearGas` for values of 1 Tgas and above.
+///
+/// Formatting does not allocate.
+///
+/// # Examples
+/// ```
+/// use near_gas::{DisplayRounding, GasUnit, NearGas, UnitStyle};
+///
+/// let gas = NearGas::from_gas(1_234_567_890_123);
+/// assert_eq!(gas.display().to_string(), "1.3 Tgas");
+/// assert_eq!(
+///     gas.display()
+///         .unit(GasUnit::Ggas)
+///         .rounding(DisplayRounding::Exact)
+///         .to_string(),
+///     "1234.567890123 Ggas"
+/// );
+/// assert_eq!(
+///     gas.display()
+///         .precision(3)
+///         .rounding(DisplayRounding::Down)
+///         .unit_style(UnitStyle::Long)
+///         .to_string(),
+///     "1.234 teragas"
+/// );
+/// assert_eq!(
+///     NearGas::from_ggas(1_500).display().auto_unit().precision(3).trim_trailing_zeros().to_string(),
+///     "1.5 Tgas"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NearGasDisplay {
+    gas: NearGas,
+    unit: Option<GasUnit>,
+    precision: usize,
+    rounding: DisplayRounding,
+    unit_style: UnitStyle,
+    trim_trailing_zeros: bool,
+}
+
+impl NearGasDisplay {
+    pub(crate) const fn new(gas: NearGas) -> Self {
+        Self {
+            gas,
+            unit: Some(GasUnit::Tgas),
+            precision: 1,
+            rounding: DisplayRounding::Up,
+            unit_style: UnitStyle::Short,
+            trim_trailing_zeros: false,
+        }
+    }
+
+    /// Renders the value in the given unit.
+    pub const fn unit(mut self, unit: GasUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Renders the value in the largest unit that is not greater than the value itself.
+    /// Zero is rendered in gas.
+    pub const fn auto_unit(mut self) -> Self {
+        self.unit = None;
+        self
+    }
+
+    /// Sets the number of digits after the decimal point.
+    /// With [`DisplayRounding::Exact`], this is the minimum number of digits.
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets how the value is rounded to the requested precision.
+    pub const fn rounding(mut self, rounding: DisplayRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets whether the unit is rendered with its short or long name.
+    pub const fn unit_style(mut self, unit_style: UnitStyle) -> Self {
+        self.unit_style = unit_style;
+        self
+    }
+
+    /// Removes trailing zeros after the decimal point, and the decimal point itself if nothing is left.
+    pub const fn trim_trailing_zeros(mut self) -> Self {
+        self.trim_trailing_zeros = true;
+        self
+    }
+
+    /// Returns the unit the value is going to be rendered in.
+    pub const fn resolved_unit(&self) -> GasUnit {
+        if let Some(unit) = self.unit {
+            return unit;
+        }
+        let mut i = GasUnit::ALL.len();
+        while i > 1 {
+            i -= 1;
+            if self.gas.as_gas() >= GasUnit::ALL[i].multiplier() {
+                return GasUnit::ALL[i];
+            }
+        }
+        GasUnit::Gas
+    }
+}
+
+/// Width, fill and alignment flags of the formatter are honored, e.g. `{:>12}`.
+impl core::fmt::Display for NearGasDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_padded(f, |w| self.render(w))
+    }
+}
+
+impl NearGasDisplay {
+    fn render(&self, f: &mut dyn core::fmt::Write) -> core::fmt::Result {
+        let unit = self.resolved_unit();
+        let gas = self.gas.as_gas();
+        let decimals = unit.decimals() as usize;
+
+        // Value scaled to `digits` fractional digits, where `digits <= decimals`;
+        // the remaining `padding` requested digits are always zero.
+        let (whole, mut fraction, mut digits, mut padding) = match self.rounding {
+            DisplayRounding::Exact => {
+                let mut fraction = gas % unit.multiplier();
+                let mut digits = decimals;
+                while digits > self.precision && fraction % 10 == 0 {
+                    fraction /= 10;
+                    digits -= 1;
+                }
+                (
+                    gas / unit.multiplier(),
+                    fraction,
+                    digits,
+                    self.precision.saturating_sub(digits),
+                )
+            }
+            rounding => {
+                let digits = self.precision.min(decimals);
+                let divisor = 10u64.pow((decimals - digits) as u32);
+                let mode = match rounding {
+                    DisplayRounding::Down => RoundingMode::Down,
+                    DisplayRounding::Up => RoundingMode::Up,
+                    _ => RoundingMode::Nearest,
+                };
+                let scaled = mode.div(gas, divisor);
+                let scale = 10u64.pow(digits as u32);
+                (
+                    scaled / scale,
+                    scaled % scale,
+                    digits,
+                    self.precision - digits,
+                )
+            }
+        };
+
+        if self.trim_trailing_zeros {
+            if fraction == 0 {
+                digits = 0;
+                padding = 0;
+            } else {
+                padding = 0;
+                while fraction % 10 == 0 {
+                    fraction /= 10;
+                    digits -= 1;
+                }
+            }
+        }
+
+        write!(f, "{}", whole)?;
+        if digits + padding > 0 {
+            write!(f, ".")?;
+            if digits > 0 {
+                write!(f, "{:0width$}", fraction, width = digits)?;
+            }
+            for _ in 0..padding {
+                f.write_char('0')?;
+            }
+        }
+        let name = match self.unit_style {
+            UnitStyle::Short => unit.name(),
+            UnitStyle::Long => unit.long_name(),
+        };
+        write!(f, " {}", name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DisplayRounding, GasUnit, NearGas, UnitStyle};
+
+    #[test]
+    fn rounding() {
+        let gas = NearGas::from_gas(1_250_000_000_000);
+        for (rounding, expected) in [
+            (DisplayRounding::Down, "1.2 Tgas"),
+            (DisplayRounding::Up, "1.3 Tgas"),
+            (DisplayRounding::Nearest, "1.3 Tgas"),
+            (DisplayRounding::Exact, "1.25 Tgas"),
+        ] {
+            assert_eq!(gas.display().rounding(rounding).to_string(), expected);
+        }
+        assert_eq!(
+            NearGas::from_gas(1_249_999_999_999)
+                .display()
+                .rounding(DisplayRounding::Nearest)
+                .to_string(),
+            "1.2 Tgas"
+        );
+    }
+
+    #[test]
+    fn precision() {
+        let gas = NearGas::from_gas(1_500_000_000);
+        assert_eq!(
+            gas.display().unit(GasUnit::Ggas).precision(0).to_string(),
+            "2 Ggas"
+        );
+        assert_eq!(
+            gas.display().unit(GasUnit::Ggas).precision(12).to_string(),
+            "1.500000000000 Ggas"
+        );
+        assert_eq!(
+            gas.display()
+                .unit(GasUnit::Ggas)
+                .precision(12)
+                .rounding(DisplayRounding::Exact)
+                .to_string(),
+            "1.500000000000 Ggas"
+        );
+        assert_eq!(
+            gas.display()
+                .unit(GasUnit::Ggas)
+                .precision(12)
+                .trim_trailing_zeros()
+                .to_string(),
+            "1.5 Ggas"
+        );
+        assert_eq!(
+            NearGas::from_gas(7)
+                .display()
+                .unit(GasUnit::Gas)
+                .precision(2)
+                .to_string(),
+            "7.00 gas"
+        );
+    }
+
+    #[test]
+    fn carry_into_whole_part() {
+        assert_eq!(
+            NearGas::from_gas(999_999_999_999).display().to_string(),
+            "1.0 Tgas"
+        );
+        assert_eq!(
+            NearGas::from_gas(u64::MAX)
+                .display()
+                .unit(GasUnit::Pgas)
+                .precision(0)
+                .to_string(),
+            "18447 Pgas"
+        );
+        assert_eq!(
+            NearGas::from_gas(u64::MAX)
+                .display()
+                .unit(GasUnit::Kgas)
+                .precision(2)
+                .to_string(),
+            "18446744073709551.62 Kgas"
+        );
+    }
+
+    #[test]
+    fn auto_unit() {
+        for (gas, expected) in [
+            (NearGas::from_gas(0), "0 gas"),
+            (NearGas::from_gas(999), "999 gas"),
+            (NearGas::from_gas(1_000), "1 Kgas"),
+            (NearGas::from_gas(2_500_000), "2.5 Mgas"),
+            (NearGas::from_ggas(5_500), "5.5 Tgas"),
+            (NearGas::from_pgas(1), "1 Pgas"),
+        ] {
+            assert_eq!(
+                gas.display()
+                    .auto_unit()
+                    .rounding(DisplayRounding::Exact)
+                    .precision(0)
+                    .to_string(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn kilo_and_mega_gas() {
+        let gas = NearGas::from_gas(2_345_678);
+        assert_eq!(
+            gas.display().unit(GasUnit::Kgas).precision(2).to_string(),
+            "2345.68 Kgas"
+        );
+        assert_eq!(
+            gas.display()
+                .unit(GasUnit::Mgas)
+                .unit_style(UnitStyle::Long)
+                .rounding(DisplayRounding::Down)
+                .to_string(),
+            "2.3 megagas"
+        );
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(
+            format!("{:>10}|", NearGas::from_tgas(3).display()),
+            "  3.0 Tgas|"
+        );
+    }
+
+    #[test]
+    fn unit_style() {
+        assert_eq!(
+            NearGas::from_tgas(3)
+                .display()
+                .unit_style(UnitStyle::Long)
+                .trim_trailing_zeros()
+                .to_string(),
+            "3 teragas"
+        );
+    }
+}
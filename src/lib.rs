@@ -14,6 +14,13 @@
 //!
 //! # Crate features
 //!
+//! * **std** (default) -
+//!   Enables `std` support. Implies `alloc`. Without it the crate is `#![no_std]`.
+//!
+//! * **alloc** (enabled by `std` and `borsh`) -
+//!   Enables APIs that need a heap allocator, like `NearGas::distribute`, `GasBudget` and
+//!   `GasMeter`. Parsing and formatting never allocate.
+//!
 //! * **borsh** (optional) -
 //!   When enabled allows `NearGas` to serialized and deserialized by `borsh`.
 //!
@@ -25,12 +32,61 @@
 //!
 //! * **interactive-clap** (optional) -
 //!   Implements `interactive_clap::ToCli` for `NearGas`.
+//!
+//! * **expr** (optional) -
+//!   Enables `NearGas::parse_expr` to evaluate arithmetic expressions like `5 Tgas + 300 Ggas`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod bounded;
+#[cfg(feature = "alloc")]
+mod budget;
+mod delta;
 mod error;
+#[cfg(feature = "expr")]
+mod expr;
+mod format;
+mod macros;
+#[cfg(feature = "alloc")]
+mod meter;
+mod non_zero;
+mod parse;
+mod price;
+mod rounding;
 mod trait_impls;
+mod unit;
 mod utils;
+mod weight;
+
+pub use self::bounded::{BoundedGas, GasLimits, PrepaidGas};
+#[cfg(feature = "alloc")]
+pub use self::budget::{
+    AllocationKind, GasAllocation, GasBudget, GasBudgetBreakdown, GasBudgetError,
+};
+pub use self::delta::NearGasDelta;
+pub use self::error::{
+    FloatConversionError, GasLimitExceeded, NearGasError, NearGasErrorKind, NearGasErrorReport,
+};
+pub use self::format::{DisplayRounding, NearGasDisplay, UnitStyle};
+#[cfg(feature = "alloc")]
+pub use self::meter::{GasCheckpoint, GasExceeded, GasMeter, GasSpan};
+pub use self::non_zero::NonZeroNearGas;
+pub use self::parse::{ParseMode, ParseOptions};
+pub use self::price::GasPrice;
+pub use self::rounding::RoundingMode;
+pub use self::unit::GasUnit;
+pub use self::weight::GasWeight;
 
-pub use self::error::NearGasError;
-pub use self::utils::DecimalNumberParsingError;
+/// The former error type for malformed numbers. Malformed numbers are now reported as a
+/// [`NearGasError`] whose [`NearGasError::kind`] tells what is wrong, e.g.
+/// [`NearGasErrorKind::InvalidDigit`] or [`NearGasErrorKind::TooManyFractionalDigits`].
+#[deprecated(
+    since = "0.4.0",
+    note = "number parsing errors are reported as `NearGasError`, match on `NearGasError::kind` instead"
+)]
+pub type DecimalNumberParsingError = NearGasError;
 
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -44,251 +100,1604 @@ pub struct NearGas {
     inner: u64,
 }
 
-const ONE_PETA_GAS: u64 = 10u64.pow(15);
-const ONE_TERA_GAS: u64 = 10u64.pow(12);
-const ONE_GIGA_GAS: u64 = 10u64.pow(9);
+const ONE_PETA_GAS: u64 = GasUnit::Pgas.multiplier();
+const ONE_TERA_GAS: u64 = GasUnit::Tgas.multiplier();
+const ONE_GIGA_GAS: u64 = GasUnit::Ggas.multiplier();
+const ONE_MEGA_GAS: u64 = GasUnit::Mgas.multiplier();
+const ONE_KILO_GAS: u64 = GasUnit::Kgas.multiplier();
 
 impl NearGas {
     /// Creates a new `NearGas` from the specified number of whole peta Gas.
     ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, in both debug and release builds.
+    /// When used to initialize a `const`, overflow is reported as a compile-time error:
+    ///
+    /// ```compile_fail
+    /// use near_gas::NearGas;
+    ///
+    /// const TOO_MUCH_GAS: NearGas = NearGas::from_pgas(20_000);
+    /// # let _ = TOO_MUCH_GAS;
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let tera_gas = NearGas::from_pgas(1);
+    ///
+    /// assert_eq!(tera_gas.as_gas(), 1_000_000_000_000_000);
+    /// ```
+    pub const fn from_pgas(inner: u64) -> Self {
+        match Self::checked_from_pgas(inner) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_pgas overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole peta Gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::checked_from_pgas(1), Some(NearGas::from_gas(1_000_000_000_000_000)));
+    /// assert_eq!(NearGas::checked_from_pgas(20_000), None);
+    /// ```
+    pub const fn checked_from_pgas(inner: u64) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(ONE_PETA_GAS) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole peta Gas,
+    /// saturating at `u64::MAX` gas instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::saturating_from_pgas(1), NearGas::from_gas(1_000_000_000_000_000));
+    /// assert_eq!(NearGas::saturating_from_pgas(20_000), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_from_pgas(inner: u64) -> Self {
+        Self::from_gas(inner.saturating_mul(ONE_PETA_GAS))
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole tera Gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, in both debug and release builds.
+    /// When used to initialize a `const`, overflow is reported as a compile-time error:
+    ///
+    /// ```compile_fail
+    /// use near_gas::NearGas;
+    ///
+    /// const TOO_MUCH_GAS: NearGas = NearGas::from_tgas(20_000_000);
+    /// # let _ = TOO_MUCH_GAS;
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let tera_gas = NearGas::from_tgas(5);
+    ///
+    /// assert_eq!(tera_gas.as_gas(), 5 * 1_000_000_000_000);
+    /// ```
+    pub const fn from_tgas(inner: u64) -> Self {
+        match Self::checked_from_tgas(inner) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_tgas overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole tera Gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::checked_from_tgas(1), Some(NearGas::from_gas(1_000_000_000_000)));
+    /// assert_eq!(NearGas::checked_from_tgas(20_000_000), None);
+    /// ```
+    pub const fn checked_from_tgas(inner: u64) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(ONE_TERA_GAS) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole tera Gas,
+    /// saturating at `u64::MAX` gas instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::saturating_from_tgas(1), NearGas::from_gas(1_000_000_000_000));
+    /// assert_eq!(NearGas::saturating_from_tgas(20_000_000), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_from_tgas(inner: u64) -> Self {
+        Self::from_gas(inner.saturating_mul(ONE_TERA_GAS))
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole giga Gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, in both debug and release builds.
+    /// When used to initialize a `const`, overflow is reported as a compile-time error:
+    ///
+    /// ```compile_fail
+    /// use near_gas::NearGas;
+    ///
+    /// const TOO_MUCH_GAS: NearGas = NearGas::from_ggas(20_000_000_000);
+    /// # let _ = TOO_MUCH_GAS;
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let giga_gas = NearGas::from_ggas(5);
+    ///
+    /// assert_eq!(giga_gas.as_gas(), 5 * 1_000_000_000);
+    /// ```
+    pub const fn from_ggas(inner: u64) -> Self {
+        match Self::checked_from_ggas(inner) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_ggas overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole giga Gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::checked_from_ggas(1), Some(NearGas::from_gas(1_000_000_000)));
+    /// assert_eq!(NearGas::checked_from_ggas(20_000_000_000), None);
+    /// ```
+    pub const fn checked_from_ggas(inner: u64) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(ONE_GIGA_GAS) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole giga Gas,
+    /// saturating at `u64::MAX` gas instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::saturating_from_ggas(1), NearGas::from_gas(1_000_000_000));
+    /// assert_eq!(NearGas::saturating_from_ggas(20_000_000_000), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_from_ggas(inner: u64) -> Self {
+        Self::from_gas(inner.saturating_mul(ONE_GIGA_GAS))
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole mega Gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, in both debug and release builds.
+    /// When used to initialize a `const`, overflow is reported as a compile-time error:
+    ///
+    /// ```compile_fail
+    /// use near_gas::NearGas;
+    ///
+    /// const TOO_MUCH_GAS: NearGas = NearGas::from_mgas(20_000_000_000_000);
+    /// # let _ = TOO_MUCH_GAS;
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let mega_gas = NearGas::from_mgas(5);
+    ///
+    /// assert_eq!(mega_gas.as_gas(), 5 * 1_000_000);
+    /// ```
+    pub const fn from_mgas(inner: u64) -> Self {
+        match Self::checked_from_mgas(inner) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_mgas overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole mega Gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::checked_from_mgas(1), Some(NearGas::from_gas(1_000_000)));
+    /// assert_eq!(NearGas::checked_from_mgas(20_000_000_000_000), None);
+    /// ```
+    pub const fn checked_from_mgas(inner: u64) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(ONE_MEGA_GAS) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole mega Gas,
+    /// saturating at `u64::MAX` gas instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::saturating_from_mgas(1), NearGas::from_gas(1_000_000));
+    /// assert_eq!(NearGas::saturating_from_mgas(20_000_000_000_000), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_from_mgas(inner: u64) -> Self {
+        Self::from_gas(inner.saturating_mul(ONE_MEGA_GAS))
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole kilo Gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, in both debug and release builds.
+    /// When used to initialize a `const`, overflow is reported as a compile-time error:
+    ///
+    /// ```compile_fail
+    /// use near_gas::NearGas;
+    ///
+    /// const TOO_MUCH_GAS: NearGas = NearGas::from_kgas(20_000_000_000_000_000);
+    /// # let _ = TOO_MUCH_GAS;
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let kilo_gas = NearGas::from_kgas(5);
+    ///
+    /// assert_eq!(kilo_gas.as_gas(), 5 * 1_000);
+    /// ```
+    pub const fn from_kgas(inner: u64) -> Self {
+        match Self::checked_from_kgas(inner) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_kgas overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole kilo Gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::checked_from_kgas(1), Some(NearGas::from_gas(1_000)));
+    /// assert_eq!(NearGas::checked_from_kgas(20_000_000_000_000_000), None);
+    /// ```
+    pub const fn checked_from_kgas(inner: u64) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(ONE_KILO_GAS) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole kilo Gas,
+    /// saturating at `u64::MAX` gas instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::saturating_from_kgas(1), NearGas::from_gas(1_000));
+    /// assert_eq!(NearGas::saturating_from_kgas(20_000_000_000_000_000), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_from_kgas(inner: u64) -> Self {
+        Self::from_gas(inner.saturating_mul(ONE_KILO_GAS))
+    }
+
+    /// Creates a new `NearGas` from a fractional number of peta Gas, rounding the resulting
+    /// amount of gas according to `mode`.
+    ///
+    /// Floating point values cannot represent most decimal fractions exactly, so
+    /// `RoundingMode::Nearest` is usually what you want.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{FloatConversionError, NearGas, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     NearGas::try_from_pgas_f64(0.0125, RoundingMode::Nearest),
+    ///     Ok(NearGas::from_gas(12_500_000_000_000))
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_pgas_f64(-1.0, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::Negative)
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_pgas_f64(f64::NAN, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::NaN)
+    /// );
+    /// ```
+    pub const fn try_from_pgas_f64(
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        Self::try_from_f64_with_multiplier(value, ONE_PETA_GAS, mode)
+    }
+
+    /// Creates a new `NearGas` from a fractional number of tera Gas, rounding the resulting
+    /// amount of gas according to `mode`.
+    ///
+    /// Floating point values cannot represent most decimal fractions exactly, so
+    /// `RoundingMode::Nearest` is usually what you want.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{FloatConversionError, NearGas, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     NearGas::try_from_tgas_f64(12.5, RoundingMode::Nearest),
+    ///     Ok(NearGas::from_gas(12_500_000_000_000))
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_tgas_f64(-1.0, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::Negative)
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_tgas_f64(f64::NAN, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::NaN)
+    /// );
+    /// ```
+    pub const fn try_from_tgas_f64(
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        Self::try_from_f64_with_multiplier(value, ONE_TERA_GAS, mode)
+    }
+
+    /// Creates a new `NearGas` from a fractional number of giga Gas, rounding the resulting
+    /// amount of gas according to `mode`.
+    ///
+    /// Floating point values cannot represent most decimal fractions exactly, so
+    /// `RoundingMode::Nearest` is usually what you want.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{FloatConversionError, NearGas, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     NearGas::try_from_ggas_f64(12.5, RoundingMode::Nearest),
+    ///     Ok(NearGas::from_gas(12_500_000_000))
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_ggas_f64(-1.0, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::Negative)
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_ggas_f64(f64::NAN, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::NaN)
+    /// );
+    /// ```
+    pub const fn try_from_ggas_f64(
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        Self::try_from_f64_with_multiplier(value, ONE_GIGA_GAS, mode)
+    }
+
+    /// Creates a new `NearGas` from a fractional number of mega Gas, rounding the resulting
+    /// amount of gas according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{FloatConversionError, NearGas, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     NearGas::try_from_mgas_f64(12.5, RoundingMode::Nearest),
+    ///     Ok(NearGas::from_gas(12_500_000))
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_mgas_f64(-1.0, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::Negative)
+    /// );
+    /// ```
+    pub const fn try_from_mgas_f64(
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        Self::try_from_f64_with_multiplier(value, ONE_MEGA_GAS, mode)
+    }
+
+    /// Creates a new `NearGas` from a fractional number of kilo Gas, rounding the resulting
+    /// amount of gas according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{FloatConversionError, NearGas, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     NearGas::try_from_kgas_f64(12.5, RoundingMode::Nearest),
+    ///     Ok(NearGas::from_gas(12_500))
+    /// );
+    /// assert_eq!(
+    ///     NearGas::try_from_kgas_f64(-1.0, RoundingMode::Nearest),
+    ///     Err(FloatConversionError::Negative)
+    /// );
+    /// ```
+    pub const fn try_from_kgas_f64(
+        value: f64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        Self::try_from_f64_with_multiplier(value, ONE_KILO_GAS, mode)
+    }
+
+    const fn try_from_f64_with_multiplier(
+        value: f64,
+        multiplier: u64,
+        mode: RoundingMode,
+    ) -> Result<Self, FloatConversionError> {
+        if value.is_nan() {
+            return Err(FloatConversionError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(FloatConversionError::Infinite);
+        }
+        if value < 0.0 {
+            return Err(FloatConversionError::Negative);
+        }
+        let gas = value * multiplier as f64;
+        // `u64::MAX as f64` rounds up to 2^64, which is the first value that does not fit.
+        if gas >= u64::MAX as f64 {
+            return Err(FloatConversionError::Overflow);
+        }
+        let whole = gas as u64;
+        let fraction = gas - whole as f64;
+        let round_up = match mode {
+            RoundingMode::Down => false,
+            RoundingMode::Up => fraction > 0.0,
+            RoundingMode::Nearest => fraction >= 0.5,
+        };
+        // Any `f64` below 2^64 is at most 2^64 - 2048, so adding one cannot overflow.
+        Ok(Self::from_gas(if round_up { whole + 1 } else { whole }))
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole units of gas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit into `u64` gas, see [`NearGas::checked_from_unit`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    ///
+    /// assert_eq!(NearGas::from_unit(5, GasUnit::Tgas), NearGas::from_tgas(5));
+    /// assert_eq!(NearGas::from_unit(5, GasUnit::Gas), NearGas::from_gas(5));
+    /// ```
+    pub const fn from_unit(inner: u64, unit: GasUnit) -> Self {
+        match Self::checked_from_unit(inner, unit) {
+            Some(gas) => gas,
+            None => panic!("NearGas::from_unit overflowed u64 gas"),
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole units of gas,
+    /// returning None if the result does not fit into `u64` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    ///
+    /// assert_eq!(NearGas::checked_from_unit(5, GasUnit::Ggas), Some(NearGas::from_ggas(5)));
+    /// assert_eq!(NearGas::checked_from_unit(u64::MAX, GasUnit::Kgas), None);
+    /// ```
+    pub const fn checked_from_unit(inner: u64, unit: GasUnit) -> Option<Self> {
+        if let Some(gas) = inner.checked_mul(unit.multiplier()) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `NearGas` from the specified number of whole Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let gas = NearGas::from_gas(5 * 1_000_000_000_000);
+    ///
+    /// assert_eq!(gas.as_tgas(), 5);
+    /// ```
+    pub const fn from_gas(inner: u64) -> Self {
+        Self { inner }
+    }
+
+    /// Parses a `NearGas` from a string, like `FromStr` does, but with the given options.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     default_unit: Some(GasUnit::Gas),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(NearGas::parse_with("1000", options), Ok(NearGas::from_gas(1000)));
+    /// assert!(NearGas::parse_with("1000", ParseOptions::default()).is_err());
+    /// ```
+    pub const fn parse_with(s: &str, options: ParseOptions) -> Result<Self, NearGasError> {
+        parse::parse_near_gas(s, options)
+    }
+
+    /// Parses a `NearGas` from a string exactly like `FromStr` does, but in a `const` context.
+    ///
+    /// See also the [`gas!`] macro, which rejects invalid literals at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// const CALLBACK_GAS: NearGas = match NearGas::parse_const("5.5 Tgas") {
+    ///     Ok(gas) => gas,
+    ///     Err(_) => panic!("invalid gas amount"),
+    /// };
+    /// assert_eq!(CALLBACK_GAS, NearGas::from_ggas(5_500));
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, NearGasError> {
+        parse::parse_near_gas(s, ParseOptions::new())
+    }
+
+    /// Parses and evaluates an arithmetic expression over amounts of gas.
+    ///
+    /// Amounts are written as accepted by `FromStr`, e.g. `1.5 Tgas`. They can be added and
+    /// subtracted, multiplied and divided by whole numbers, and grouped with parentheses.
+    /// Division rounds down. All the arithmetic is checked: overflow, a negative result or a
+    /// division by zero is reported as an error spanning the offending operation.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasErrorKind};
+    ///
+    /// assert_eq!(NearGas::parse_expr("5 Tgas + 300 Ggas"), Ok(NearGas::from_ggas(5_300)));
+    /// assert_eq!(NearGas::parse_expr("3 * 10 Tgas"), Ok(NearGas::from_tgas(30)));
+    /// assert_eq!(NearGas::parse_expr("(300 Tgas - 5 Tgas) / 2"), Ok(NearGas::from_ggas(147_500)));
+    ///
+    /// let err = NearGas::parse_expr("1 Tgas - 2 Tgas").unwrap_err();
+    /// assert_eq!(err.kind(), NearGasErrorKind::Underflow);
+    /// assert_eq!(err.span(), 0..15);
+    /// ```
+    #[cfg(feature = "expr")]
+    pub fn parse_expr(s: &str) -> Result<Self, NearGasError> {
+        expr::parse_expr(s)
+    }
+
+    /// Returns whether the gas value is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use near_gas::NearGas;
+    /// assert!(NearGas::from_gas(0).is_zero());
+    /// assert!(!NearGas::from_gas(1).is_zero());
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.as_gas() == 0
+    }
+
+    /// Converts the amount into `NonZeroNearGas`, returning None if it is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// assert_eq!(NearGas::from_tgas(1).to_non_zero().map(|gas| gas.get()), Some(NearGas::from_tgas(1)));
+    /// assert_eq!(NearGas::from_gas(0).to_non_zero(), None);
+    /// ```
+    pub const fn to_non_zero(self) -> Option<NonZeroNearGas> {
+        NonZeroNearGas::new(self)
+    }
+
+    /// Returns the total number of whole Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(12345);
+    /// assert_eq!(neargas.as_gas(), 12345);
+    /// ```
+    pub const fn as_gas(self) -> u64 {
+        self.inner
+    }
+
+    /// Returns the total number of a whole part of the given unit contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    /// let neargas = NearGas::from_gas(1_999_999_999_999);
+    /// assert_eq!(neargas.to_unit(GasUnit::Tgas), 1);
+    /// assert_eq!(neargas.to_unit(GasUnit::Ggas), 1_999);
+    /// assert_eq!(neargas.to_unit(GasUnit::Gas), 1_999_999_999_999);
+    /// ```
+    pub const fn to_unit(self, unit: GasUnit) -> u64 {
+        self.inner / unit.multiplier()
+    }
+
+    /// Returns the total number of a whole part of kilo Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(1 * 1_000);
+    /// assert_eq!(neargas.as_kgas(), 1);
+    /// ```
+    pub const fn as_kgas(self) -> u64 {
+        self.inner / ONE_KILO_GAS
+    }
+
+    /// Returns the total number of a whole part of mega Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(1 * 1_000_000);
+    /// assert_eq!(neargas.as_mgas(), 1);
+    /// ```
+    pub const fn as_mgas(self) -> u64 {
+        self.inner / ONE_MEGA_GAS
+    }
+
+    /// Returns the total number of a whole part of giga Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(1 * 1_000_000_000);
+    /// assert_eq!(neargas.as_ggas(), 1);
+    /// ```
+    pub const fn as_ggas(self) -> u64 {
+        self.inner / ONE_GIGA_GAS
+    }
+
+    /// Returns the total number of a whole part of tera Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(1 * 1_000_000_000_000);
+    /// assert_eq!(neargas.as_tgas(), 1);
+    /// ```
+    pub const fn as_tgas(self) -> u64 {
+        self.inner / ONE_TERA_GAS
+    }
+
+    /// Returns the total number of a whole part of peta Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(1 * 1_000_000_000_000_000);
+    /// assert_eq!(neargas.as_pgas(), 1);
+    /// ```
+    pub const fn as_pgas(self) -> u64 {
+        self.inner / ONE_PETA_GAS
+    }
+
+    /// Returns the number of kilo Gas contained by this `NearGas`, rounded according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, RoundingMode};
+    /// let neargas = NearGas::from_gas(1_500);
+    /// assert_eq!(neargas.as_kgas_rounded(RoundingMode::Down), 1);
+    /// assert_eq!(neargas.as_kgas_rounded(RoundingMode::Up), 2);
+    /// assert_eq!(neargas.as_kgas_rounded(RoundingMode::Nearest), 2);
+    /// ```
+    pub const fn as_kgas_rounded(self, mode: RoundingMode) -> u64 {
+        mode.div(self.inner, ONE_KILO_GAS)
+    }
+
+    /// Returns the number of kilo Gas contained by this `NearGas`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_000).as_kgas_ceil(), 1);
+    /// assert_eq!(NearGas::from_gas(1_000 + 1).as_kgas_ceil(), 2);
+    /// ```
+    pub const fn as_kgas_ceil(self) -> u64 {
+        self.as_kgas_rounded(RoundingMode::Up)
+    }
+
+    /// Returns the whole number of kilo Gas contained by this `NearGas` together with the remaining Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (whole, remainder) = NearGas::from_gas(1_999).div_rem_kgas();
+    /// assert_eq!(whole, 1);
+    /// assert_eq!(remainder, NearGas::from_gas(999));
+    /// ```
+    pub const fn div_rem_kgas(self) -> (u64, NearGas) {
+        (
+            self.inner / ONE_KILO_GAS,
+            NearGas::from_gas(self.inner % ONE_KILO_GAS),
+        )
+    }
+
+    /// Returns the amount of kilo Gas contained by this `NearGas` as a floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500).as_kgas_f64(), 1.5);
+    /// ```
+    pub fn as_kgas_f64(self) -> f64 {
+        let (whole, remainder) = self.div_rem_kgas();
+        whole as f64 + remainder.inner as f64 / ONE_KILO_GAS as f64
+    }
+
+    /// Returns the amount of kilo Gas contained by this `NearGas` as a single-precision floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500).as_kgas_f32(), 1.5);
+    /// ```
+    pub fn as_kgas_f32(self) -> f32 {
+        self.as_kgas_f64() as f32
+    }
+
+    /// Returns the number of mega Gas contained by this `NearGas`, rounded according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, RoundingMode};
+    /// let neargas = NearGas::from_gas(1_500_000);
+    /// assert_eq!(neargas.as_mgas_rounded(RoundingMode::Down), 1);
+    /// assert_eq!(neargas.as_mgas_rounded(RoundingMode::Up), 2);
+    /// assert_eq!(neargas.as_mgas_rounded(RoundingMode::Nearest), 2);
+    /// ```
+    pub const fn as_mgas_rounded(self, mode: RoundingMode) -> u64 {
+        mode.div(self.inner, ONE_MEGA_GAS)
+    }
+
+    /// Returns the number of mega Gas contained by this `NearGas`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_000_000).as_mgas_ceil(), 1);
+    /// assert_eq!(NearGas::from_gas(1_000_000 + 1).as_mgas_ceil(), 2);
+    /// ```
+    pub const fn as_mgas_ceil(self) -> u64 {
+        self.as_mgas_rounded(RoundingMode::Up)
+    }
+
+    /// Returns the whole number of mega Gas contained by this `NearGas` together with the remaining Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (whole, remainder) = NearGas::from_gas(1_999_999).div_rem_mgas();
+    /// assert_eq!(whole, 1);
+    /// assert_eq!(remainder, NearGas::from_gas(999_999));
+    /// ```
+    pub const fn div_rem_mgas(self) -> (u64, NearGas) {
+        (
+            self.inner / ONE_MEGA_GAS,
+            NearGas::from_gas(self.inner % ONE_MEGA_GAS),
+        )
+    }
+
+    /// Returns the amount of mega Gas contained by this `NearGas` as a floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000).as_mgas_f64(), 1.5);
+    /// ```
+    pub fn as_mgas_f64(self) -> f64 {
+        let (whole, remainder) = self.div_rem_mgas();
+        whole as f64 + remainder.inner as f64 / ONE_MEGA_GAS as f64
+    }
+
+    /// Returns the amount of mega Gas contained by this `NearGas` as a single-precision floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000).as_mgas_f32(), 1.5);
+    /// ```
+    pub fn as_mgas_f32(self) -> f32 {
+        self.as_mgas_f64() as f32
+    }
+
+    /// Returns the number of giga Gas contained by this `NearGas`, rounded according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, RoundingMode};
+    /// let neargas = NearGas::from_gas(1_500_000_000);
+    /// assert_eq!(neargas.as_ggas_rounded(RoundingMode::Down), 1);
+    /// assert_eq!(neargas.as_ggas_rounded(RoundingMode::Up), 2);
+    /// assert_eq!(neargas.as_ggas_rounded(RoundingMode::Nearest), 2);
+    /// ```
+    pub const fn as_ggas_rounded(self, mode: RoundingMode) -> u64 {
+        mode.div(self.inner, ONE_GIGA_GAS)
+    }
+
+    /// Returns the number of giga Gas contained by this `NearGas`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_000_000_000).as_ggas_ceil(), 1);
+    /// assert_eq!(NearGas::from_gas(1_000_000_000 + 1).as_ggas_ceil(), 2);
+    /// ```
+    pub const fn as_ggas_ceil(self) -> u64 {
+        self.as_ggas_rounded(RoundingMode::Up)
+    }
+
+    /// Returns the whole number of giga Gas contained by this `NearGas` together with the remaining Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (whole, remainder) = NearGas::from_gas(1_999_999_999).div_rem_ggas();
+    /// assert_eq!(whole, 1);
+    /// assert_eq!(remainder, NearGas::from_gas(999_999_999));
+    /// ```
+    pub const fn div_rem_ggas(self) -> (u64, NearGas) {
+        (
+            self.inner / ONE_GIGA_GAS,
+            NearGas::from_gas(self.inner % ONE_GIGA_GAS),
+        )
+    }
+
+    /// Returns the amount of giga Gas contained by this `NearGas` as a floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000).as_ggas_f64(), 1.5);
+    /// ```
+    pub fn as_ggas_f64(self) -> f64 {
+        let (whole, remainder) = self.div_rem_ggas();
+        whole as f64 + remainder.inner as f64 / ONE_GIGA_GAS as f64
+    }
+
+    /// Returns the amount of giga Gas contained by this `NearGas` as a single-precision floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000).as_ggas_f32(), 1.5);
+    /// ```
+    pub fn as_ggas_f32(self) -> f32 {
+        self.as_ggas_f64() as f32
+    }
+
+    /// Returns the number of tera Gas contained by this `NearGas`, rounded according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, RoundingMode};
+    /// let neargas = NearGas::from_gas(1_500_000_000_000);
+    /// assert_eq!(neargas.as_tgas_rounded(RoundingMode::Down), 1);
+    /// assert_eq!(neargas.as_tgas_rounded(RoundingMode::Up), 2);
+    /// assert_eq!(neargas.as_tgas_rounded(RoundingMode::Nearest), 2);
+    /// ```
+    pub const fn as_tgas_rounded(self, mode: RoundingMode) -> u64 {
+        mode.div(self.inner, ONE_TERA_GAS)
+    }
+
+    /// Returns the number of tera Gas contained by this `NearGas`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_000_000_000_000).as_tgas_ceil(), 1);
+    /// assert_eq!(NearGas::from_gas(1_000_000_000_000 + 1).as_tgas_ceil(), 2);
+    /// ```
+    pub const fn as_tgas_ceil(self) -> u64 {
+        self.as_tgas_rounded(RoundingMode::Up)
+    }
+
+    /// Returns the whole number of tera Gas contained by this `NearGas` together with the remaining Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (whole, remainder) = NearGas::from_gas(1_999_999_999_999).div_rem_tgas();
+    /// assert_eq!(whole, 1);
+    /// assert_eq!(remainder, NearGas::from_gas(999_999_999_999));
+    /// ```
+    pub const fn div_rem_tgas(self) -> (u64, NearGas) {
+        (
+            self.inner / ONE_TERA_GAS,
+            NearGas::from_gas(self.inner % ONE_TERA_GAS),
+        )
+    }
+
+    /// Returns the amount of tera Gas contained by this `NearGas` as a floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000_000).as_tgas_f64(), 1.5);
+    /// ```
+    pub fn as_tgas_f64(self) -> f64 {
+        let (whole, remainder) = self.div_rem_tgas();
+        whole as f64 + remainder.inner as f64 / ONE_TERA_GAS as f64
+    }
+
+    /// Returns the amount of tera Gas contained by this `NearGas` as a single-precision floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000_000).as_tgas_f32(), 1.5);
+    /// ```
+    pub fn as_tgas_f32(self) -> f32 {
+        self.as_tgas_f64() as f32
+    }
+
+    /// Returns the number of peta Gas contained by this `NearGas`, rounded according to `mode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, RoundingMode};
+    /// let neargas = NearGas::from_gas(1_500_000_000_000_000);
+    /// assert_eq!(neargas.as_pgas_rounded(RoundingMode::Down), 1);
+    /// assert_eq!(neargas.as_pgas_rounded(RoundingMode::Up), 2);
+    /// assert_eq!(neargas.as_pgas_rounded(RoundingMode::Nearest), 2);
+    /// ```
+    pub const fn as_pgas_rounded(self, mode: RoundingMode) -> u64 {
+        mode.div(self.inner, ONE_PETA_GAS)
+    }
+
+    /// Returns the number of peta Gas contained by this `NearGas`, rounded up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_000_000_000_000_000).as_pgas_ceil(), 1);
+    /// assert_eq!(NearGas::from_gas(1_000_000_000_000_000 + 1).as_pgas_ceil(), 2);
+    /// ```
+    pub const fn as_pgas_ceil(self) -> u64 {
+        self.as_pgas_rounded(RoundingMode::Up)
+    }
+
+    /// Returns the whole number of peta Gas contained by this `NearGas` together with the remaining Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (whole, remainder) = NearGas::from_gas(1_999_999_999_999_999).div_rem_pgas();
+    /// assert_eq!(whole, 1);
+    /// assert_eq!(remainder, NearGas::from_gas(999_999_999_999_999));
+    /// ```
+    pub const fn div_rem_pgas(self) -> (u64, NearGas) {
+        (
+            self.inner / ONE_PETA_GAS,
+            NearGas::from_gas(self.inner % ONE_PETA_GAS),
+        )
+    }
+
+    /// Returns the amount of peta Gas contained by this `NearGas` as a floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000_000_000).as_pgas_f64(), 1.5);
+    /// ```
+    pub fn as_pgas_f64(self) -> f64 {
+        let (whole, remainder) = self.div_rem_pgas();
+        whole as f64 + remainder.inner as f64 / ONE_PETA_GAS as f64
+    }
+
+    /// Returns the amount of peta Gas contained by this `NearGas` as a single-precision floating point number.
+    ///
+    /// The result is approximate and intended for presentation purposes (e.g. charts), not for accounting.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000_000_000).as_pgas_f32(), 1.5);
+    /// ```
+    pub fn as_pgas_f32(self) -> f32 {
+        self.as_pgas_f64() as f32
+    }
+
+    /// Returns a configurable formatter for this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    ///
+    /// let gas = NearGas::from_gas(5_500_000_000);
+    /// assert_eq!(gas.display().unit(GasUnit::Ggas).precision(2).to_string(), "5.50 Ggas");
+    /// ```
+    pub const fn display(self) -> NearGasDisplay {
+        NearGasDisplay::new(self)
+    }
+
+    /// Returns a formatter that renders this `NearGas` exactly, without any rounding.
+    ///
+    /// The value is rendered in the largest unit not greater than the value, with the fewest
+    /// fractional digits required, so the output always parses back to the identical value.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// let gas = NearGas::from_gas(1_000_000_100_000);
+    /// assert_eq!(gas.to_string(), "1.1 Tgas");
+    /// assert_eq!(gas.exact().to_string(), "1.0000001 Tgas");
+    /// assert_eq!(gas.exact().to_string().parse::<NearGas>().unwrap(), gas);
+    /// ```
+    pub const fn exact(self) -> NearGasDisplay {
+        self.display()
+            .auto_unit()
+            .precision(0)
+            .rounding(DisplayRounding::Exact)
+    }
+
+    /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::u64;
+    /// assert_eq!(NearGas::from_gas(u64::MAX -2).checked_add(NearGas::from_gas(2)), Some(NearGas::from_gas(u64::MAX)));
+    /// assert_eq!(NearGas::from_gas(u64::MAX -2).checked_add(NearGas::from_gas(3)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: NearGas) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_add(rhs.as_gas()) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(2).checked_sub(NearGas::from_gas(2)), Some(NearGas::from_gas(0)));
+    /// assert_eq!(NearGas::from_gas(2).checked_sub(NearGas::from_gas(3)), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: NearGas) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_sub(rhs.as_gas()) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked integer multiplication. Computes self * rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::u64;
+    /// assert_eq!(NearGas::from_gas(2).checked_mul(2), Some(NearGas::from_gas(4)));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).checked_mul(2), None)
+    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_mul(rhs) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked integer division. Computes self / rhs, returning None if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(10).checked_div(2), Some(NearGas::from_gas(5)));
+    /// assert_eq!(NearGas::from_gas(2).checked_div(0), None);
+    /// ```
+    pub const fn checked_div(self, rhs: u64) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_div(rhs) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked integer remainder. Computes self % rhs, returning None if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(10).checked_rem(3), Some(NearGas::from_gas(1)));
+    /// assert_eq!(NearGas::from_gas(10).checked_rem(0), None);
+    /// ```
+    pub const fn checked_rem(self, rhs: u64) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_rem(rhs) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Calculates the smallest value greater than or equal to self that is a multiple of rhs.
+    /// Returns None if rhs is zero or the operation would result in overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(16).checked_next_multiple_of(8), Some(NearGas::from_gas(16)));
+    /// assert_eq!(NearGas::from_gas(23).checked_next_multiple_of(8), Some(NearGas::from_gas(24)));
+    /// assert_eq!(NearGas::from_gas(1).checked_next_multiple_of(0), None);
+    /// assert_eq!(NearGas::from_gas(u64::MAX).checked_next_multiple_of(2), None);
+    /// ```
+    pub const fn checked_next_multiple_of(self, rhs: u64) -> Option<Self> {
+        if let Some(gas) = self.as_gas().checked_next_multiple_of(rhs) {
+            Some(Self::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Calculates the quotient of self and rhs, rounding the result towards positive infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if rhs is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(10).div_ceil(3), NearGas::from_gas(4));
+    /// assert_eq!(NearGas::from_gas(9).div_ceil(3), NearGas::from_gas(3));
+    /// ```
+    pub const fn div_ceil(self, rhs: u64) -> NearGas {
+        NearGas::from_gas(self.as_gas().div_ceil(rhs))
+    }
+
+    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(5).saturating_add(NearGas::from_gas(5)), NearGas::from_gas(10));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).saturating_add(NearGas::from_gas(1)), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_add(self, rhs: NearGas) -> NearGas {
+        NearGas::from_gas(self.as_gas().saturating_add(rhs.as_gas()))
+    }
+
+    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(5).saturating_sub(NearGas::from_gas(2)), NearGas::from_gas(3));
+    /// assert_eq!(NearGas::from_gas(1).saturating_sub(NearGas::from_gas(2)), NearGas::from_gas(0));
+    /// ```
+    pub const fn saturating_sub(self, rhs: NearGas) -> NearGas {
+        NearGas::from_gas(self.as_gas().saturating_sub(rhs.as_gas()))
+    }
+
+    /// Saturating integer multiplication. Computes self * rhs, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::u64;
+    /// assert_eq!(NearGas::from_gas(2).saturating_mul(5), NearGas::from_gas(10));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).saturating_mul(2), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_mul(self, rhs: u64) -> NearGas {
+        NearGas::from_gas(self.as_gas().saturating_mul(rhs))
+    }
+
+    /// Saturating integer division. Computes self / rhs, saturating at the numeric bounds instead of overflowing.
+    ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    ///
-    /// let tera_gas = NearGas::from_pgas(1);
-    ///
-    /// assert_eq!(tera_gas.as_gas(), 1_000_000_000_000_000);
+    /// assert_eq!(NearGas::from_gas(10).saturating_div(2), NearGas::from_gas(5));
+    /// assert_eq!(NearGas::from_gas(10).saturating_div(0), NearGas::from_gas(0))
     /// ```
-    pub const fn from_pgas(mut inner: u64) -> Self {
-        inner *= ONE_PETA_GAS;
-        Self { inner }
+    pub const fn saturating_div(self, rhs: u64) -> NearGas {
+        if rhs == 0 {
+            return NearGas::from_gas(0);
+        }
+        NearGas::from_gas(self.as_gas().saturating_div(rhs))
     }
 
-    /// Creates a new `NearGas` from the specified number of whole tera Gas.
+    /// Wrapping integer addition. Computes self + rhs, wrapping around at the boundary of the type.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    ///
-    /// let tera_gas = NearGas::from_tgas(5);
-    ///
-    /// assert_eq!(tera_gas.as_gas(), 5 * 1_000_000_000_000);
+    /// assert_eq!(NearGas::from_gas(5).wrapping_add(NearGas::from_gas(5)), NearGas::from_gas(10));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).wrapping_add(NearGas::from_gas(2)), NearGas::from_gas(1));
     /// ```
-    pub const fn from_tgas(mut inner: u64) -> Self {
-        inner *= ONE_TERA_GAS;
-        Self { inner }
+    pub const fn wrapping_add(self, rhs: NearGas) -> NearGas {
+        NearGas::from_gas(self.as_gas().wrapping_add(rhs.as_gas()))
     }
 
-    /// Creates a new `NearGas` from the specified number of whole giga Gas.
+    /// Wrapping integer subtraction. Computes self - rhs, wrapping around at the boundary of the type.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    ///
-    /// let giga_gas = NearGas::from_ggas(5);
-    ///
-    /// assert_eq!(giga_gas.as_gas(), 5 * 1_000_000_000);
+    /// assert_eq!(NearGas::from_gas(5).wrapping_sub(NearGas::from_gas(2)), NearGas::from_gas(3));
+    /// assert_eq!(NearGas::from_gas(1).wrapping_sub(NearGas::from_gas(2)), NearGas::from_gas(u64::MAX));
     /// ```
-    pub const fn from_ggas(mut inner: u64) -> Self {
-        inner *= ONE_GIGA_GAS;
-        Self { inner }
+    pub const fn wrapping_sub(self, rhs: NearGas) -> NearGas {
+        NearGas::from_gas(self.as_gas().wrapping_sub(rhs.as_gas()))
     }
 
-    /// Creates a new `NearGas` from the specified number of whole Gas.
+    /// Wrapping integer multiplication. Computes self * rhs, wrapping around at the boundary of the type.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(2).wrapping_mul(5), NearGas::from_gas(10));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).wrapping_mul(2), NearGas::from_gas(u64::MAX - 1));
+    /// ```
+    pub const fn wrapping_mul(self, rhs: u64) -> NearGas {
+        NearGas::from_gas(self.as_gas().wrapping_mul(rhs))
+    }
+
+    /// Wrapping integer division. Computes self / rhs. Division of unsigned types never wraps,
+    /// this method exists for parity with the `u64` API.
     ///
-    /// let gas = NearGas::from_gas(5 * 1_000_000_000_000);
+    /// # Panics
     ///
-    /// assert_eq!(gas.as_tgas(), 5);
+    /// This function will panic if rhs is zero.
+    ///
+    /// # Examples
     /// ```
-    pub const fn from_gas(inner: u64) -> Self {
-        Self { inner }
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(10).wrapping_div(3), NearGas::from_gas(3));
+    /// ```
+    pub const fn wrapping_div(self, rhs: u64) -> NearGas {
+        NearGas::from_gas(self.as_gas().wrapping_div(rhs))
     }
 
-    /// Returns whether the gas value is zero.
+    /// Wrapping integer remainder. Computes self % rhs. Remainder of unsigned types never wraps,
+    /// this method exists for parity with the `u64` API.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if rhs is zero.
     ///
     /// # Examples
     /// ```
-    /// # use near_gas::NearGas;
-    /// assert!(NearGas::from_gas(0).is_zero());
-    /// assert!(!NearGas::from_gas(1).is_zero());
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(10).wrapping_rem(3), NearGas::from_gas(1));
     /// ```
-    pub const fn is_zero(&self) -> bool {
-        self.as_gas() == 0
+    pub const fn wrapping_rem(self, rhs: u64) -> NearGas {
+        NearGas::from_gas(self.as_gas().wrapping_rem(rhs))
     }
 
-    /// Returns the total number of whole Gas contained by this `NearGas`.
+    /// Calculates self + rhs. Returns a tuple of the addition along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// let neargas = NearGas::from_gas(12345);
-    /// assert_eq!(neargas.as_gas(), 12345);
+    /// assert_eq!(NearGas::from_gas(5).overflowing_add(NearGas::from_gas(2)), (NearGas::from_gas(7), false));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).overflowing_add(NearGas::from_gas(1)), (NearGas::from_gas(0), true));
     /// ```
-    pub const fn as_gas(self) -> u64 {
-        self.inner
+    pub const fn overflowing_add(self, rhs: NearGas) -> (NearGas, bool) {
+        let (gas, overflowed) = self.as_gas().overflowing_add(rhs.as_gas());
+        (NearGas::from_gas(gas), overflowed)
     }
 
-    /// Returns the total number of a whole part of giga Gas contained by this `NearGas`.
+    /// Calculates self - rhs. Returns a tuple of the subtraction along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// let neargas = NearGas::from_gas(1 * 1_000_000_000);
-    /// assert_eq!(neargas.as_ggas(), 1);
+    /// assert_eq!(NearGas::from_gas(5).overflowing_sub(NearGas::from_gas(2)), (NearGas::from_gas(3), false));
+    /// assert_eq!(NearGas::from_gas(0).overflowing_sub(NearGas::from_gas(1)), (NearGas::from_gas(u64::MAX), true));
     /// ```
-    pub const fn as_ggas(self) -> u64 {
-        self.inner / ONE_GIGA_GAS
+    pub const fn overflowing_sub(self, rhs: NearGas) -> (NearGas, bool) {
+        let (gas, overflowed) = self.as_gas().overflowing_sub(rhs.as_gas());
+        (NearGas::from_gas(gas), overflowed)
     }
 
-    /// Returns the total number of a whole part of tera Gas contained by this `NearGas`.
+    /// Calculates self * rhs. Returns a tuple of the multiplication along with a boolean indicating whether
+    /// an arithmetic overflow would occur. If an overflow would have occurred then the wrapped value is returned.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// let neargas = NearGas::from_gas(1 * 1_000_000_000_000);
-    /// assert_eq!(neargas.as_tgas(), 1);
+    /// assert_eq!(NearGas::from_gas(5).overflowing_mul(2), (NearGas::from_gas(10), false));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).overflowing_mul(2), (NearGas::from_gas(u64::MAX - 1), true));
     /// ```
-    pub const fn as_tgas(self) -> u64 {
-        self.inner / ONE_TERA_GAS
+    pub const fn overflowing_mul(self, rhs: u64) -> (NearGas, bool) {
+        let (gas, overflowed) = self.as_gas().overflowing_mul(rhs);
+        (NearGas::from_gas(gas), overflowed)
     }
 
-    /// Returns the total number of a whole part of peta Gas contained by this `NearGas`.
+    /// Strict integer addition. Computes self + rhs, panicking if overflow occurred,
+    /// regardless of whether overflow checks are enabled.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// let neargas = NearGas::from_gas(1 * 1_000_000_000_000_000);
-    /// assert_eq!(neargas.as_pgas(), 1);
+    /// assert_eq!(NearGas::from_gas(5).strict_add(NearGas::from_gas(5)), NearGas::from_gas(10));
     /// ```
-    pub const fn as_pgas(self) -> u64 {
-        self.inner / ONE_PETA_GAS
+    ///
+    /// ```should_panic
+    /// use near_gas::NearGas;
+    /// let _ = NearGas::from_gas(u64::MAX).strict_add(NearGas::from_gas(1));
+    /// ```
+    pub const fn strict_add(self, rhs: NearGas) -> NearGas {
+        match self.checked_add(rhs) {
+            Some(gas) => gas,
+            None => panic!("attempt to add NearGas with overflow"),
+        }
     }
 
-    /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
+    /// Strict integer subtraction. Computes self - rhs, panicking if overflow occurred,
+    /// regardless of whether overflow checks are enabled.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// use std::u64;
-    /// assert_eq!(NearGas::from_gas(u64::MAX -2).checked_add(NearGas::from_gas(2)), Some(NearGas::from_gas(u64::MAX)));
-    /// assert_eq!(NearGas::from_gas(u64::MAX -2).checked_add(NearGas::from_gas(3)), None);
+    /// assert_eq!(NearGas::from_gas(5).strict_sub(NearGas::from_gas(2)), NearGas::from_gas(3));
     /// ```
-    pub const fn checked_add(self, rhs: NearGas) -> Option<Self> {
-        if let Some(gas) = self.as_gas().checked_add(rhs.as_gas()) {
-            Some(Self::from_gas(gas))
-        } else {
-            None
+    ///
+    /// ```should_panic
+    /// use near_gas::NearGas;
+    /// let _ = NearGas::from_gas(0).strict_sub(NearGas::from_gas(1));
+    /// ```
+    pub const fn strict_sub(self, rhs: NearGas) -> NearGas {
+        match self.checked_sub(rhs) {
+            Some(gas) => gas,
+            None => panic!("attempt to subtract NearGas with overflow"),
         }
     }
 
-    /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
+    /// Strict integer multiplication. Computes self * rhs, panicking if overflow occurred,
+    /// regardless of whether overflow checks are enabled.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// assert_eq!(NearGas::from_gas(2).checked_sub(NearGas::from_gas(2)), Some(NearGas::from_gas(0)));
-    /// assert_eq!(NearGas::from_gas(2).checked_sub(NearGas::from_gas(3)), None);
+    /// assert_eq!(NearGas::from_gas(5).strict_mul(2), NearGas::from_gas(10));
     /// ```
-    pub const fn checked_sub(self, rhs: NearGas) -> Option<Self> {
-        if let Some(gas) = self.as_gas().checked_sub(rhs.as_gas()) {
-            Some(Self::from_gas(gas))
-        } else {
-            None
+    ///
+    /// ```should_panic
+    /// use near_gas::NearGas;
+    /// let _ = NearGas::from_gas(u64::MAX).strict_mul(2);
+    /// ```
+    pub const fn strict_mul(self, rhs: u64) -> NearGas {
+        match self.checked_mul(rhs) {
+            Some(gas) => gas,
+            None => panic!("attempt to multiply NearGas with overflow"),
         }
     }
 
-    /// Checked integer multiplication. Computes self * rhs, returning None if overflow occurred.
+    /// Strict integer division. Computes self / rhs, panicking if rhs == 0.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// use std::u64;
-    /// assert_eq!(NearGas::from_gas(2).checked_mul(2), Some(NearGas::from_gas(4)));
-    /// assert_eq!(NearGas::from_gas(u64::MAX).checked_mul(2), None)
-    pub const fn checked_mul(self, rhs: u64) -> Option<Self> {
-        if let Some(gas) = self.as_gas().checked_mul(rhs) {
-            Some(Self::from_gas(gas))
-        } else {
-            None
+    /// assert_eq!(NearGas::from_gas(10).strict_div(2), NearGas::from_gas(5));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use near_gas::NearGas;
+    /// let _ = NearGas::from_gas(10).strict_div(0);
+    /// ```
+    pub const fn strict_div(self, rhs: u64) -> NearGas {
+        match self.checked_div(rhs) {
+            Some(gas) => gas,
+            None => panic!("attempt to divide NearGas by zero"),
         }
     }
 
-    /// Checked integer division. Computes self / rhs, returning None if rhs == 0.
+    /// Strict integer remainder. Computes self % rhs, panicking if rhs == 0.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// assert_eq!(NearGas::from_gas(10).checked_div(2), Some(NearGas::from_gas(5)));
-    /// assert_eq!(NearGas::from_gas(2).checked_div(0), None);
+    /// assert_eq!(NearGas::from_gas(10).strict_rem(3), NearGas::from_gas(1));
     /// ```
-    pub const fn checked_div(self, rhs: u64) -> Option<Self> {
-        if let Some(gas) = self.as_gas().checked_div(rhs) {
-            Some(Self::from_gas(gas))
-        } else {
-            None
+    ///
+    /// ```should_panic
+    /// use near_gas::NearGas;
+    /// let _ = NearGas::from_gas(10).strict_rem(0);
+    /// ```
+    pub const fn strict_rem(self, rhs: u64) -> NearGas {
+        match self.checked_rem(rhs) {
+            Some(gas) => gas,
+            None => {
+                panic!("attempt to calculate the remainder of NearGas with a divisor of zero")
+            }
         }
     }
 
-    /// Saturating integer addition. Computes self + rhs, saturating at the numeric bounds instead of overflowing.
+    /// Computes the absolute difference between self and other.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// assert_eq!(NearGas::from_gas(5).saturating_add(NearGas::from_gas(5)), NearGas::from_gas(10));
-    /// assert_eq!(NearGas::from_gas(u64::MAX).saturating_add(NearGas::from_gas(1)), NearGas::from_gas(u64::MAX));
+    /// assert_eq!(NearGas::from_gas(100).abs_diff(NearGas::from_gas(80)), NearGas::from_gas(20));
+    /// assert_eq!(NearGas::from_gas(80).abs_diff(NearGas::from_gas(100)), NearGas::from_gas(20));
     /// ```
-    pub const fn saturating_add(self, rhs: NearGas) -> NearGas {
-        NearGas::from_gas(self.as_gas().saturating_add(rhs.as_gas()))
+    pub const fn abs_diff(self, other: NearGas) -> NearGas {
+        NearGas::from_gas(self.as_gas().abs_diff(other.as_gas()))
     }
 
-    /// Saturating integer subtraction. Computes self - rhs, saturating at the numeric bounds instead of overflowing.
+    /// Computes the signed difference `self - other`.
     ///
     /// # Examples
     /// ```
     /// use near_gas::NearGas;
-    /// assert_eq!(NearGas::from_gas(5).saturating_sub(NearGas::from_gas(2)), NearGas::from_gas(3));
-    /// assert_eq!(NearGas::from_gas(1).saturating_sub(NearGas::from_gas(2)), NearGas::from_gas(0));
+    ///
+    /// let diff = NearGas::from_ggas(600).signed_diff(NearGas::from_tgas(1));
+    /// assert_eq!(diff.as_gas(), -400_000_000_000);
+    /// assert!(diff.is_negative());
     /// ```
-    pub const fn saturating_sub(self, rhs: NearGas) -> NearGas {
-        NearGas::from_gas(self.as_gas().saturating_sub(rhs.as_gas()))
+    pub const fn signed_diff(self, other: NearGas) -> NearGasDelta {
+        if self.inner >= other.inner {
+            NearGasDelta::positive(NearGas::from_gas(self.inner - other.inner))
+        } else {
+            NearGasDelta::negative(NearGas::from_gas(other.inner - self.inner))
+        }
     }
 
-    /// Saturating integer multiplication. Computes self * rhs, saturating at the numeric bounds instead of overflowing.
+    /// Checked addition of a signed delta. Computes self + delta, returning None if the result is
+    /// negative or overflows `u64`.
     ///
     /// # Examples
     /// ```
-    /// use near_gas::NearGas;
-    /// use std::u64;
-    /// assert_eq!(NearGas::from_gas(2).saturating_mul(5), NearGas::from_gas(10));
-    /// assert_eq!(NearGas::from_gas(u64::MAX).saturating_mul(2), NearGas::from_gas(u64::MAX));
+    /// use near_gas::{NearGas, NearGasDelta};
+    ///
+    /// let refund = NearGasDelta::negative(NearGas::from_ggas(400));
+    /// assert_eq!(NearGas::from_tgas(1).checked_add_signed(refund), Some(NearGas::from_ggas(600)));
+    /// assert_eq!(NearGas::from_ggas(300).checked_add_signed(refund), None);
     /// ```
-    pub const fn saturating_mul(self, rhs: u64) -> NearGas {
-        NearGas::from_gas(self.as_gas().saturating_mul(rhs))
+    pub const fn checked_add_signed(self, delta: NearGasDelta) -> Option<NearGas> {
+        let gas = self.inner as i128 + delta.as_gas();
+        if gas < 0 || gas > u64::MAX as i128 {
+            None
+        } else {
+            Some(NearGas::from_gas(gas as u64))
+        }
     }
 
-    /// Saturating integer division. Computes self / rhs, saturating at the numeric bounds instead of overflowing.
+    /// Returns the cost of the gas at `price` in yoctoNEAR, or None if it overflows `u128`.
     ///
     /// # Examples
     /// ```
-    /// use near_gas::NearGas;
-    /// assert_eq!(NearGas::from_gas(10).saturating_div(2), NearGas::from_gas(5));
-    /// assert_eq!(NearGas::from_gas(10).saturating_div(0), NearGas::from_gas(0))
+    /// use near_gas::{GasPrice, NearGas};
+    ///
+    /// let price = GasPrice::from_yocto_per_gas(100_000_000);
+    /// assert_eq!(NearGas::from_tgas(300).cost_at(price), Some(30_000_000_000_000_000_000_000));
+    /// assert_eq!(
+    ///     NearGas::from_gas(u64::MAX).cost_at(GasPrice::from_yocto_per_gas(u128::MAX)),
+    ///     None
+    /// );
     /// ```
-    pub const fn saturating_div(self, rhs: u64) -> NearGas {
-        if rhs == 0 {
-            return NearGas::from_gas(0);
+    pub const fn cost_at(self, price: GasPrice) -> Option<u128> {
+        (self.inner as u128).checked_mul(price.as_yocto_per_gas())
+    }
+
+    /// Splits the gas among function call actions by their weights the way the runtime distributes
+    /// the gas left unused by a receipt, writing the share of `weights[i]` to `out[i]`.
+    ///
+    /// Each action receives `self / sum(weights) * weight`, and the remainder of the division goes
+    /// to the last action with a non-zero weight. If all weights are zero, no gas is distributed
+    /// and `out` is filled with zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` and `weights` have different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasWeight, NearGas};
+    ///
+    /// let weights = [GasWeight(1), GasWeight(1), GasWeight(1), GasWeight(0)];
+    /// let mut out = [NearGas::from_gas(0); 4];
+    /// NearGas::from_gas(10).distribute_into(&weights, &mut out);
+    /// assert_eq!(out.map(NearGas::as_gas), [3, 3, 4, 0]);
+    /// ```
+    pub const fn distribute_into(self, weights: &[GasWeight], out: &mut [NearGas]) {
+        assert!(
+            out.len() == weights.len(),
+            "`out` must have the same length as `weights`"
+        );
+        let mut weight_sum: u128 = 0;
+        let mut last = None;
+        let mut i = 0;
+        while i < weights.len() {
+            weight_sum += weights[i].0 as u128;
+            if weights[i].0 > 0 {
+                last = Some(i);
+            }
+            i += 1;
         }
-        NearGas::from_gas(self.as_gas().saturating_div(rhs))
+        let gas_per_weight = match weight_sum {
+            0 => 0,
+            _ => (self.inner as u128 / weight_sum) as u64,
+        };
+        let mut distributed = 0;
+        let mut i = 0;
+        while i < weights.len() {
+            // Cannot overflow, as the product does not exceed `self` for any weight.
+            let gas = gas_per_weight * weights[i].0;
+            distributed += gas;
+            out[i] = NearGas::from_gas(gas);
+            i += 1;
+        }
+        if let Some(last) = last {
+            out[last].inner += self.inner - distributed;
+        }
+    }
+
+    /// Splits the gas among function call actions by their weights the way the runtime distributes
+    /// the gas left unused by a receipt, see [`NearGas::distribute_into`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasWeight, NearGas};
+    ///
+    /// let gas = NearGas::from_tgas(100).distribute(&[GasWeight(1), GasWeight(2)]);
+    /// assert_eq!(gas, [NearGas::from_gas(33_333_333_333_333), NearGas::from_gas(66_666_666_666_667)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn distribute(self, weights: &[GasWeight]) -> alloc::vec::Vec<NearGas> {
+        let mut out = alloc::vec![NearGas::from_gas(0); weights.len()];
+        self.distribute_into(weights, &mut out);
+        out
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::NearGas;
+    use crate::{NearGas, RoundingMode};
 
     #[test]
     fn checked_add_gas() {
@@ -367,4 +1776,194 @@ mod test {
         assert_eq!(gas.saturating_div(rhs), NearGas::from_gas(5));
         assert_eq!(gas.saturating_div(another_gas), NearGas::from_gas(0));
     }
+
+    #[test]
+    fn checked_rem_gas() {
+        let gas = NearGas::from_gas(10);
+        assert_eq!(gas.checked_rem(3), Some(NearGas::from_gas(1)));
+        assert_eq!(gas.checked_rem(0), None);
+    }
+
+    #[test]
+    fn div_ceil_gas() {
+        let gas = NearGas::from_tgas(300);
+        assert_eq!(gas.div_ceil(3), NearGas::from_tgas(100));
+        assert_eq!(gas.div_ceil(7), NearGas::from_gas(42_857_142_857_143));
+        assert_eq!(
+            NearGas::from_gas(23).checked_next_multiple_of(8),
+            Some(NearGas::from_gas(24))
+        );
+    }
+
+    #[test]
+    fn wrapping_gas() {
+        let max = NearGas::from_gas(u64::MAX);
+        let one = NearGas::from_gas(1);
+        assert_eq!(max.wrapping_add(one), NearGas::from_gas(0));
+        assert_eq!(NearGas::from_gas(0).wrapping_sub(one), max);
+        assert_eq!(max.wrapping_mul(3), NearGas::from_gas(u64::MAX - 2));
+    }
+
+    #[test]
+    fn overflowing_gas() {
+        let max = NearGas::from_gas(u64::MAX);
+        let one = NearGas::from_gas(1);
+        assert_eq!(max.overflowing_add(one), (NearGas::from_gas(0), true));
+        assert_eq!(one.overflowing_add(one), (NearGas::from_gas(2), false));
+        assert_eq!(NearGas::from_gas(0).overflowing_sub(one), (max, true));
+        assert_eq!(max.overflowing_mul(1), (max, false));
+    }
+
+    #[test]
+    fn abs_diff_gas() {
+        let estimate = NearGas::from_tgas(10);
+        let actual = NearGas::from_ggas(9_600);
+        assert_eq!(estimate.abs_diff(actual), NearGas::from_ggas(400));
+        assert_eq!(actual.abs_diff(estimate), NearGas::from_ggas(400));
+    }
+
+    #[test]
+    fn strict_const() {
+        const TOTAL: NearGas = NearGas::from_tgas(5).strict_add(NearGas::from_ggas(300));
+        assert_eq!(TOTAL, NearGas::from_ggas(5_300));
+    }
+
+    #[test]
+    fn checked_from_units() {
+        assert_eq!(
+            NearGas::checked_from_pgas(18_446),
+            Some(NearGas::from_gas(18_446 * 10u64.pow(15)))
+        );
+        assert_eq!(NearGas::checked_from_pgas(18_447), None);
+        assert_eq!(
+            NearGas::checked_from_tgas(u64::MAX / 10u64.pow(12) + 1),
+            None
+        );
+        assert_eq!(
+            NearGas::checked_from_ggas(u64::MAX / 10u64.pow(9) + 1),
+            None
+        );
+        assert_eq!(
+            NearGas::checked_from_mgas(u64::MAX / 10u64.pow(6) + 1),
+            None
+        );
+        assert_eq!(
+            NearGas::checked_from_kgas(u64::MAX / 10u64.pow(3) + 1),
+            None
+        );
+        assert_eq!(
+            NearGas::saturating_from_pgas(18_447),
+            NearGas::from_gas(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn parse_const() {
+        use crate::{GasUnit, NearGasErrorKind, gas};
+
+        const CALLBACK_GAS: NearGas = gas!("5.5 Tgas");
+        assert_eq!(CALLBACK_GAS, NearGas::from_ggas(5_500));
+        assert_eq!(gas!(" 1000 gas "), NearGas::from_gas(1000));
+        assert_eq!(gas!("\u{a0}2\u{2009}Kgas"), NearGas::from_kgas(2));
+
+        const TYPO: Result<NearGas, crate::NearGasError> = NearGas::parse_const("1 Tgs");
+        let err = TYPO.unwrap_err();
+        assert_eq!(err.kind(), NearGasErrorKind::UnknownUnit);
+        assert_eq!(err.span(), 2..5);
+        assert_eq!(err.suggestion(), Some(GasUnit::Tgas));
+        assert_eq!(
+            NearGas::parse_const("20000 Pgas").unwrap_err().kind(),
+            NearGasErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn kgas_and_mgas() {
+        assert_eq!(NearGas::from_kgas(3), NearGas::from_gas(3_000));
+        assert_eq!(NearGas::from_mgas(3), NearGas::from_kgas(3_000));
+        assert_eq!(NearGas::from_ggas(3), NearGas::from_mgas(3_000));
+
+        let gas = NearGas::from_gas(2_345_678);
+        assert_eq!(gas.as_kgas(), 2_345);
+        assert_eq!(gas.as_mgas(), 2);
+        assert_eq!(gas.as_kgas_ceil(), 2_346);
+        assert_eq!(gas.as_mgas_rounded(RoundingMode::Nearest), 2);
+        assert_eq!(gas.div_rem_mgas(), (2, NearGas::from_gas(345_678)));
+        assert_eq!(gas.div_rem_kgas(), (2_345, NearGas::from_gas(678)));
+        assert_eq!(NearGas::from_kgas(2_500).as_mgas_f64(), 2.5);
+        assert_eq!(
+            NearGas::try_from_kgas_f64(1.5, RoundingMode::Down),
+            Ok(NearGas::from_gas(1_500))
+        );
+        assert_eq!(
+            NearGas::try_from_mgas_f64(0.0000015, RoundingMode::Up),
+            Ok(NearGas::from_gas(2))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "NearGas::from_pgas overflowed u64 gas")]
+    fn from_pgas_overflow() {
+        let _ = NearGas::from_pgas(std::hint::black_box(20_000));
+    }
+
+    #[test]
+    fn fractional_accessors() {
+        let gas = NearGas::from_gas(1_999_999_999_999);
+        assert_eq!(gas.as_tgas(), 1);
+        assert_eq!(gas.as_tgas_ceil(), 2);
+        assert_eq!(gas.as_tgas_rounded(RoundingMode::Nearest), 2);
+        assert_eq!(gas.div_rem_tgas(), (1, NearGas::from_gas(999_999_999_999)));
+        assert_eq!(gas.div_rem_ggas(), (1_999, NearGas::from_gas(999_999_999)));
+        assert_eq!(gas.div_rem_pgas(), (0, gas));
+        assert_eq!(NearGas::from_gas(u64::MAX).as_ggas_ceil(), 18_446_744_074);
+        assert_eq!(NearGas::from_ggas(12_500).as_tgas_f64(), 12.5);
+        assert_eq!(NearGas::from_ggas(250).as_tgas_f32(), 0.25);
+    }
+
+    #[test]
+    fn try_from_f64() {
+        use crate::FloatConversionError;
+
+        assert_eq!(
+            NearGas::try_from_tgas_f64(12.5, RoundingMode::Down),
+            Ok(NearGas::from_ggas(12_500))
+        );
+        assert_eq!(
+            NearGas::try_from_tgas_f64(0.1, RoundingMode::Nearest),
+            Ok(NearGas::from_ggas(100))
+        );
+        assert_eq!(
+            NearGas::try_from_ggas_f64(0.0000000015, RoundingMode::Down),
+            Ok(NearGas::from_gas(1))
+        );
+        assert_eq!(
+            NearGas::try_from_ggas_f64(0.0000000015, RoundingMode::Up),
+            Ok(NearGas::from_gas(2))
+        );
+        assert_eq!(
+            NearGas::try_from_ggas_f64(-0.0, RoundingMode::Up),
+            Ok(NearGas::from_gas(0))
+        );
+        assert_eq!(
+            NearGas::try_from_pgas_f64(18_446.7, RoundingMode::Nearest),
+            Ok(NearGas::from_tgas(18_446_700))
+        );
+        assert_eq!(
+            NearGas::try_from_pgas_f64(18_446.75, RoundingMode::Nearest),
+            Err(FloatConversionError::Overflow)
+        );
+        assert_eq!(
+            NearGas::try_from_tgas_f64(f64::INFINITY, RoundingMode::Nearest),
+            Err(FloatConversionError::Infinite)
+        );
+        assert_eq!(
+            NearGas::try_from_tgas_f64(f64::NEG_INFINITY, RoundingMode::Nearest),
+            Err(FloatConversionError::Infinite)
+        );
+        assert_eq!(
+            NearGas::try_from_tgas_f64(-0.001, RoundingMode::Nearest),
+            Err(FloatConversionError::Negative)
+        );
+    }
 }
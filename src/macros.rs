@@ -0,0 +1,38 @@
+/// Creates a [`NearGas`](crate::NearGas) constant from a string literal, as accepted by `FromStr`.
+///
+/// The literal is parsed at compile time, so an invalid or overflowing amount is a compile error
+/// rather than a runtime panic.
+///
+/// # Examples
+/// ```
+/// use near_gas::{gas, NearGas};
+///
+/// const CALLBACK_GAS: NearGas = gas!("5.5 Tgas");
+/// assert_eq!(CALLBACK_GAS, NearGas::from_ggas(5_500));
+/// assert_eq!(gas!("300 Tgas"), NearGas::from_tgas(300));
+/// ```
+///
+/// Invalid literals are rejected at compile time:
+///
+/// ```compile_fail
+/// use near_gas::{gas, NearGas};
+///
+/// const CALLBACK_GAS: NearGas = gas!("5.5 Tgs");
+/// ```
+///
+/// ```compile_fail
+/// use near_gas::{gas, NearGas};
+///
+/// const TOO_MUCH_GAS: NearGas = gas!("20000 Pgas");
+/// ```
+#[macro_export]
+macro_rules! gas {
+    ($literal:expr $(,)?) => {
+        const {
+            match $crate::NearGas::parse_const($literal) {
+                Ok(gas) => gas,
+                Err(err) => panic!("{}", err.kind().description()),
+            }
+        }
+    };
+}
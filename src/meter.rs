@@ -0,0 +1,361 @@
+use alloc::vec::Vec;
+
+use crate::NearGas;
+
+/// A running gas counter for simulating a function call off-chain, modeled after the gas counter
+/// of the runtime.
+///
+/// The meter tracks two amounts:
+/// * burnt gas, charged with [`GasMeter::charge`] for the work the call does itself, which may
+///   not exceed the burnt gas limit of a single function call;
+/// * used gas, the burnt gas plus the gas attached to promises with [`GasMeter::attach`], which
+///   may not exceed the prepaid gas.
+///
+/// When a charge does not fit, the meter returns a [`GasExceeded`] error and, like the runtime,
+/// consumes all the gas it can, so every further charge fails as well.
+///
+/// Named checkpoints split the execution into [`GasSpan`]s for profiling.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasExceeded, GasMeter, NearGas};
+///
+/// let mut meter = GasMeter::new(NearGas::from_tgas(30), NearGas::from_tgas(300));
+/// meter.checkpoint("setup");
+/// meter.charge(NearGas::from_tgas(2)).unwrap();
+/// meter.checkpoint("transfer");
+/// meter.charge(NearGas::from_tgas(3)).unwrap();
+/// meter.attach(NearGas::from_tgas(20)).unwrap();
+///
+/// assert_eq!(meter.burnt(), NearGas::from_tgas(5));
+/// assert_eq!(meter.used(), NearGas::from_tgas(25));
+/// assert_eq!(meter.remaining(), NearGas::from_tgas(5));
+/// assert_eq!(meter.burnt_since("transfer"), Some(NearGas::from_tgas(3)));
+///
+/// let err = meter.charge(NearGas::from_tgas(6)).unwrap_err();
+/// assert!(matches!(err, GasExceeded::Prepaid { .. }));
+/// assert_eq!(meter.remaining(), NearGas::from_gas(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasMeter<'a> {
+    burnt: NearGas,
+    used: NearGas,
+    prepaid: NearGas,
+    limit: NearGas,
+    checkpoints: Vec<GasCheckpoint<'a>>,
+}
+
+impl<'a> GasMeter<'a> {
+    /// Creates a meter for a function call with `prepaid` gas that may burn at most `limit` gas.
+    pub fn new(prepaid: NearGas, limit: NearGas) -> Self {
+        Self {
+            burnt: NearGas::from_gas(0),
+            used: NearGas::from_gas(0),
+            prepaid,
+            limit,
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Returns the gas burnt so far.
+    pub const fn burnt(&self) -> NearGas {
+        self.burnt
+    }
+
+    /// Returns the gas used so far, the burnt gas plus the gas attached to promises.
+    pub const fn used(&self) -> NearGas {
+        self.used
+    }
+
+    /// Returns the prepaid gas.
+    pub const fn prepaid(&self) -> NearGas {
+        self.prepaid
+    }
+
+    /// Returns the maximum amount of gas that may be burnt.
+    pub const fn limit(&self) -> NearGas {
+        self.limit
+    }
+
+    /// Returns the largest amount of gas that can still be charged.
+    pub fn remaining(&self) -> NearGas {
+        self.prepaid
+            .saturating_sub(self.used)
+            .min(self.limit.saturating_sub(self.burnt))
+    }
+
+    /// Burns `gas`, failing if the burnt gas would exceed the limit or the used gas would exceed
+    /// the prepaid gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasExceeded, GasMeter, NearGas};
+    ///
+    /// let mut meter = GasMeter::new(NearGas::from_tgas(300), NearGas::from_tgas(10));
+    /// meter.charge(NearGas::from_tgas(8)).unwrap();
+    /// assert_eq!(
+    ///     meter.charge(NearGas::from_tgas(3)),
+    ///     Err(GasExceeded::Limit {
+    ///         requested: NearGas::from_tgas(3),
+    ///         available: NearGas::from_tgas(2),
+    ///         limit: NearGas::from_tgas(10),
+    ///     })
+    /// );
+    /// assert_eq!(meter.burnt(), NearGas::from_tgas(10));
+    /// ```
+    pub fn charge(&mut self, gas: NearGas) -> Result<(), GasExceeded> {
+        match (self.burnt.checked_add(gas), self.used.checked_add(gas)) {
+            (Some(burnt), Some(used)) if burnt <= self.limit && used <= self.prepaid => {
+                self.burnt = burnt;
+                self.used = used;
+                Ok(())
+            }
+            _ => Err(self.exhaust(gas, true)),
+        }
+    }
+
+    /// Uses `gas` without burning it, e.g. for gas attached to a promise, failing if the used gas
+    /// would exceed the prepaid gas.
+    pub fn attach(&mut self, gas: NearGas) -> Result<(), GasExceeded> {
+        match self.used.checked_add(gas) {
+            Some(used) if used <= self.prepaid => {
+                self.used = used;
+                Ok(())
+            }
+            _ => Err(self.exhaust(gas, false)),
+        }
+    }
+
+    /// Consumes as much of `requested` as fits, like the runtime does when it runs out of gas,
+    /// and returns the error describing the limit that was hit.
+    fn exhaust(&mut self, requested: NearGas, burn: bool) -> GasExceeded {
+        let burnt_limit_exceeded = burn
+            && self
+                .burnt
+                .checked_add(requested)
+                .is_none_or(|burnt| burnt > self.limit);
+        let err = if burnt_limit_exceeded {
+            GasExceeded::Limit {
+                requested,
+                available: self.limit.saturating_sub(self.burnt),
+                limit: self.limit,
+            }
+        } else {
+            GasExceeded::Prepaid {
+                requested,
+                available: self.prepaid.saturating_sub(self.used),
+                prepaid: self.prepaid,
+            }
+        };
+        if burn {
+            self.burnt = self
+                .burnt
+                .saturating_add(requested)
+                .min(self.limit)
+                .min(self.prepaid);
+        }
+        self.used = self
+            .used
+            .saturating_add(requested)
+            .min(self.prepaid)
+            .max(self.burnt);
+        err
+    }
+
+    /// Records a checkpoint named `name` at the current burnt and used gas, which ends the
+    /// current span and starts a new one.
+    pub fn checkpoint(&mut self, name: &'a str) {
+        self.checkpoints.push(GasCheckpoint {
+            name,
+            burnt: self.burnt,
+            used: self.used,
+        });
+    }
+
+    /// Returns all checkpoints, in the order they were recorded.
+    pub fn checkpoints(&self) -> &[GasCheckpoint<'a>] {
+        &self.checkpoints
+    }
+
+    /// Returns the gas burnt since the last checkpoint named `name`.
+    pub fn burnt_since(&self, name: &str) -> Option<NearGas> {
+        self.checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.name == name)
+            .map(|checkpoint| self.burnt.saturating_sub(checkpoint.burnt))
+    }
+
+    /// Returns the span started by every checkpoint, which ends at the next checkpoint or, for
+    /// the last one, at the current state of the meter.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasMeter, GasSpan, NearGas};
+    ///
+    /// let mut meter = GasMeter::new(NearGas::from_tgas(300), NearGas::from_tgas(300));
+    /// meter.checkpoint("a");
+    /// meter.charge(NearGas::from_tgas(1)).unwrap();
+    /// meter.checkpoint("b");
+    /// meter.attach(NearGas::from_tgas(5)).unwrap();
+    ///
+    /// let spans: Vec<GasSpan> = meter.spans().collect();
+    /// assert_eq!(spans[0].name, "a");
+    /// assert_eq!(spans[0].burnt, NearGas::from_tgas(1));
+    /// assert_eq!(spans[1].used, NearGas::from_tgas(5));
+    /// ```
+    pub fn spans(&self) -> impl Iterator<Item = GasSpan<'a>> + '_ {
+        let ends = self
+            .checkpoints
+            .iter()
+            .skip(1)
+            .map(|checkpoint| (checkpoint.burnt, checkpoint.used))
+            .chain([(self.burnt, self.used)]);
+        self.checkpoints
+            .iter()
+            .zip(ends)
+            .map(|(start, (burnt, used))| GasSpan {
+                name: start.name,
+                burnt: burnt.saturating_sub(start.burnt),
+                used: used.saturating_sub(start.used),
+            })
+    }
+}
+
+/// The state of a [`GasMeter`] recorded by [`GasMeter::checkpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasCheckpoint<'a> {
+    /// The name of the checkpoint.
+    pub name: &'a str,
+    /// The gas burnt before the checkpoint.
+    pub burnt: NearGas,
+    /// The gas used before the checkpoint.
+    pub used: NearGas,
+}
+
+/// The gas burnt and used between two checkpoints of a [`GasMeter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasSpan<'a> {
+    /// The name of the checkpoint that started the span.
+    pub name: &'a str,
+    /// The gas burnt during the span.
+    pub burnt: NearGas,
+    /// The gas used during the span.
+    pub used: NearGas,
+}
+
+/// Error returned when a [`GasMeter`] runs out of gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GasExceeded {
+    /// The used gas would exceed the prepaid gas.
+    Prepaid {
+        /// The gas that was charged.
+        requested: NearGas,
+        /// The prepaid gas that was left.
+        available: NearGas,
+        /// The prepaid gas.
+        prepaid: NearGas,
+    },
+    /// The burnt gas would exceed the limit.
+    Limit {
+        /// The gas that was charged.
+        requested: NearGas,
+        /// The gas that could still be burnt.
+        available: NearGas,
+        /// The maximum amount of gas that may be burnt.
+        limit: NearGas,
+    },
+}
+
+impl core::error::Error for GasExceeded {}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasExceeded, GasMeter, GasSpan, NearGas};
+
+    #[test]
+    fn exceeding_the_prepaid_gas_exhausts_the_meter() {
+        let mut meter = GasMeter::new(NearGas::from_tgas(10), NearGas::from_tgas(300));
+        meter.charge(NearGas::from_tgas(2)).unwrap();
+        assert_eq!(
+            meter.attach(NearGas::from_tgas(9)),
+            Err(GasExceeded::Prepaid {
+                requested: NearGas::from_tgas(9),
+                available: NearGas::from_tgas(8),
+                prepaid: NearGas::from_tgas(10),
+            })
+        );
+        assert_eq!(meter.burnt(), NearGas::from_tgas(2));
+        assert_eq!(meter.used(), NearGas::from_tgas(10));
+        assert_eq!(meter.remaining(), NearGas::from_gas(0));
+        assert!(meter.charge(NearGas::from_gas(1)).is_err());
+        assert_eq!(meter.charge(NearGas::from_gas(0)), Ok(()));
+    }
+
+    #[test]
+    fn burnt_gas_never_exceeds_the_prepaid_gas() {
+        let mut meter = GasMeter::new(NearGas::from_tgas(5), NearGas::from_tgas(300));
+        assert!(matches!(
+            meter.charge(NearGas::from_gas(u64::MAX)),
+            Err(GasExceeded::Limit { .. })
+        ));
+        assert_eq!(meter.burnt(), NearGas::from_tgas(5));
+        assert_eq!(meter.used(), NearGas::from_tgas(5));
+    }
+
+    #[test]
+    fn spans() {
+        let mut meter = GasMeter::new(NearGas::from_tgas(300), NearGas::from_tgas(300));
+        assert_eq!(meter.spans().count(), 0);
+        meter.charge(NearGas::from_tgas(1)).unwrap();
+        meter.checkpoint("a");
+        meter.charge(NearGas::from_tgas(2)).unwrap();
+        meter.checkpoint("b");
+        meter.attach(NearGas::from_tgas(3)).unwrap();
+        meter.checkpoint("a");
+        meter.charge(NearGas::from_tgas(4)).unwrap();
+
+        assert_eq!(
+            meter.spans().collect::<Vec<_>>(),
+            [
+                GasSpan {
+                    name: "a",
+                    burnt: NearGas::from_tgas(2),
+                    used: NearGas::from_tgas(2),
+                },
+                GasSpan {
+                    name: "b",
+                    burnt: NearGas::from_gas(0),
+                    used: NearGas::from_tgas(3),
+                },
+                GasSpan {
+                    name: "a",
+                    burnt: NearGas::from_tgas(4),
+                    used: NearGas::from_tgas(4),
+                },
+            ]
+        );
+        assert_eq!(meter.checkpoints().len(), 3);
+        assert_eq!(meter.burnt_since("a"), Some(NearGas::from_tgas(4)));
+        assert_eq!(meter.burnt_since("b"), Some(NearGas::from_tgas(4)));
+        assert_eq!(meter.burnt_since("c"), None);
+    }
+
+    #[test]
+    fn error_display() {
+        let mut meter = GasMeter::new(NearGas::from_tgas(300), NearGas::from_tgas(10));
+        assert_eq!(
+            meter
+                .charge(NearGas::from_tgas(11))
+                .unwrap_err()
+                .to_string(),
+            "burnt gas limit exceeded: 11 Tgas requested, but only 10 Tgas of 10 Tgas are left"
+        );
+        let mut meter = GasMeter::new(NearGas::from_tgas(1), NearGas::from_tgas(10));
+        assert_eq!(
+            meter.attach(NearGas::from_tgas(2)).unwrap_err().to_string(),
+            "prepaid gas exceeded: 2 Tgas requested, but only 1 Tgas of 1 Tgas are left"
+        );
+    }
+}
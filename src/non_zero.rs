@@ -0,0 +1,195 @@
+use core::num::NonZeroU64;
+
+use crate::NearGas;
+
+/// An amount of gas that is known not to be zero, e.g. the gas attached to a function call.
+///
+/// Thanks to the `NonZeroU64` niche, `Option<NonZeroNearGas>` is the same size as `NearGas`.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, NonZeroNearGas};
+///
+/// const ATTACHED_GAS: NonZeroNearGas = NearGas::from_tgas(30).to_non_zero().unwrap();
+/// assert_eq!(NearGas::from(ATTACHED_GAS), NearGas::from_tgas(30));
+/// assert_eq!(NearGas::from_gas(0).to_non_zero(), None);
+/// assert_eq!(
+///     core::mem::size_of::<Option<NonZeroNearGas>>(),
+///     core::mem::size_of::<NearGas>()
+/// );
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct NonZeroNearGas {
+    inner: NonZeroU64,
+}
+
+impl NonZeroNearGas {
+    /// The smallest non-zero amount, a single gas.
+    pub const MIN: NonZeroNearGas = NonZeroNearGas::from_non_zero_gas(NonZeroU64::MIN);
+
+    /// The largest amount, `u64::MAX` gas.
+    pub const MAX: NonZeroNearGas = NonZeroNearGas::from_non_zero_gas(NonZeroU64::MAX);
+
+    /// Creates a `NonZeroNearGas` from `gas`, returning None if it is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NonZeroNearGas};
+    ///
+    /// assert!(NonZeroNearGas::new(NearGas::from_gas(1)).is_some());
+    /// assert!(NonZeroNearGas::new(NearGas::from_gas(0)).is_none());
+    /// ```
+    pub const fn new(gas: NearGas) -> Option<Self> {
+        match NonZeroU64::new(gas.as_gas()) {
+            Some(inner) => Some(Self { inner }),
+            None => None,
+        }
+    }
+
+    /// Creates a `NonZeroNearGas` from the specified non-zero number of gas.
+    pub const fn from_non_zero_gas(inner: NonZeroU64) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the amount as `NearGas`.
+    pub const fn get(self) -> NearGas {
+        NearGas::from_gas(self.inner.get())
+    }
+
+    /// Returns the total number of whole Gas.
+    pub const fn as_gas(self) -> u64 {
+        self.inner.get()
+    }
+
+    /// Returns the total number of whole Gas as `NonZeroU64`.
+    pub const fn as_non_zero_gas(self) -> NonZeroU64 {
+        self.inner
+    }
+
+    /// Checked addition. Computes self + rhs, returning None if overflow occurred.
+    /// The sum is never zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NonZeroNearGas};
+    ///
+    /// let gas = NonZeroNearGas::MIN;
+    /// assert_eq!(gas.checked_add(NearGas::from_gas(1)).map(NonZeroNearGas::as_gas), Some(2));
+    /// assert_eq!(NonZeroNearGas::MAX.checked_add(NearGas::from_gas(1)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: NearGas) -> Option<Self> {
+        match self.inner.checked_add(rhs.as_gas()) {
+            Some(inner) => Some(Self { inner }),
+            None => None,
+        }
+    }
+
+    /// Saturating addition. Computes self + rhs, saturating at `u64::MAX` gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NonZeroNearGas};
+    ///
+    /// assert_eq!(NonZeroNearGas::MAX.saturating_add(NearGas::from_gas(1)), NonZeroNearGas::MAX);
+    /// ```
+    pub const fn saturating_add(self, rhs: NearGas) -> Self {
+        Self {
+            inner: self.inner.saturating_add(rhs.as_gas()),
+        }
+    }
+
+    /// Checked multiplication by a non-zero factor. Computes self * rhs, returning None if
+    /// overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::num::NonZeroU64;
+    /// use near_gas::{NearGas, NonZeroNearGas};
+    ///
+    /// let gas = NearGas::from_tgas(5).to_non_zero().unwrap();
+    /// let three = NonZeroU64::new(3).unwrap();
+    /// assert_eq!(gas.checked_mul(three).map(NonZeroNearGas::get), Some(NearGas::from_tgas(15)));
+    /// assert_eq!(NonZeroNearGas::MAX.checked_mul(three), None);
+    /// ```
+    pub const fn checked_mul(self, rhs: NonZeroU64) -> Option<Self> {
+        match self.inner.checked_mul(rhs) {
+            Some(inner) => Some(Self { inner }),
+            None => None,
+        }
+    }
+
+    /// Saturating multiplication by a non-zero factor. Computes self * rhs, saturating at
+    /// `u64::MAX` gas.
+    pub const fn saturating_mul(self, rhs: NonZeroU64) -> Self {
+        Self {
+            inner: self.inner.saturating_mul(rhs),
+        }
+    }
+
+    /// Checked subtraction. Computes self - rhs, returning None if the result is zero or
+    /// overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NonZeroNearGas};
+    ///
+    /// let gas = NearGas::from_gas(2).to_non_zero().unwrap();
+    /// assert_eq!(gas.checked_sub(NearGas::from_gas(1)), Some(NonZeroNearGas::MIN));
+    /// assert_eq!(gas.checked_sub(NearGas::from_gas(2)), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: NearGas) -> Option<Self> {
+        match self.get().checked_sub(rhs) {
+            Some(gas) => Self::new(gas),
+            None => None,
+        }
+    }
+}
+
+impl From<NonZeroNearGas> for NearGas {
+    fn from(gas: NonZeroNearGas) -> Self {
+        gas.get()
+    }
+}
+
+impl From<NonZeroU64> for NonZeroNearGas {
+    fn from(gas: NonZeroU64) -> Self {
+        Self::from_non_zero_gas(gas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::num::NonZeroU64;
+
+    use crate::{NearGas, NonZeroNearGas};
+
+    #[test]
+    fn niche() {
+        assert_eq!(
+            core::mem::size_of::<Option<NonZeroNearGas>>(),
+            core::mem::size_of::<u64>()
+        );
+    }
+
+    #[test]
+    fn arithmetic_preserves_non_zeroness() {
+        let gas = NearGas::from_tgas(1).to_non_zero().unwrap();
+        assert_eq!(gas.checked_add(NearGas::from_gas(0)), Some(gas));
+        assert_eq!(gas.saturating_mul(NonZeroU64::MAX), NonZeroNearGas::MAX);
+        assert_eq!(
+            gas.checked_sub(NearGas::from_ggas(1))
+                .map(NonZeroNearGas::get),
+            Some(NearGas::from_ggas(999))
+        );
+        assert_eq!(gas.checked_sub(NearGas::from_tgas(2)), None);
+        assert_eq!(NonZeroNearGas::MIN.as_gas(), 1);
+        assert_eq!(NonZeroNearGas::MAX.get(), NearGas::from_gas(u64::MAX));
+    }
+}
@@ -0,0 +1,532 @@
+use crate::utils::{bytes_eq, parse_decimal_number, subslice, trim};
+use crate::{GasUnit, NearGas, NearGasError, NearGasErrorKind};
+
+/// Whether [`NearGas::parse_with`] accepts only the canonical notation of an amount of gas.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, ParseOptions};
+///
+/// let strict = ParseOptions::strict();
+/// assert_eq!(NearGas::parse_with("1.5 Tgas", strict), Ok(NearGas::from_ggas(1_500)));
+/// assert!(NearGas::parse_with("1.5 tgas", strict).is_err());
+/// assert!(NearGas::parse_with("1.5Tgas", strict).is_err());
+/// assert!(NearGas::parse_with(" 1.5 Tgas", strict).is_err());
+///
+/// let lenient = ParseOptions::default();
+/// assert_eq!(NearGas::parse_with(" 1.5TERAGAS ", lenient), Ok(NearGas::from_ggas(1_500)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Accepts only `<int>[.<frac>] <Unit>`: no surrounding whitespace, a single space before the
+    /// unit, the unit spelled exactly as by `Display` (e.g. `Tgas`), and no leading zeros in the
+    /// whole part. Intended for validating machine-written values such as configs.
+    ///
+    /// The number notations and the default unit of [`ParseOptions`] are ignored in this mode.
+    Strict,
+    /// Accepts what `FromStr` accepts: surrounding whitespace, any whitespace or none before the
+    /// unit, and any alias of the unit in any case (e.g. `1tgas`, `1 TERAGAS`). Intended for
+    /// human input.
+    #[default]
+    Lenient,
+}
+
+/// Options controlling how [`NearGas::parse_with`] interprets its input.
+///
+/// The default options accept exactly what `FromStr` accepts.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasUnit, NearGas, ParseOptions};
+///
+/// let options = ParseOptions {
+///     default_unit: Some(GasUnit::Gas),
+///     ..Default::default()
+/// };
+/// assert_eq!(NearGas::parse_with("1000", options), Ok(NearGas::from_gas(1000)));
+/// assert_eq!(NearGas::parse_with("1000 gas", options), Ok(NearGas::from_gas(1000)));
+/// assert_eq!(NearGas::parse_with("1 Tgas", options), Ok(NearGas::from_tgas(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// The unit of a number given without a unit. When `None`, the unit is required.
+    pub default_unit: Option<GasUnit>,
+    /// Accept `_` between digits, e.g. `300_000_000_000_000 gas`.
+    pub allow_underscores: bool,
+    /// Accept `,` separating the whole part of the number into groups of three digits,
+    /// e.g. `1,000 Tgas`.
+    pub allow_thousands_separators: bool,
+    /// Accept an exponent after the number, e.g. `3e14 gas` or `2.5E-3 Tgas`.
+    /// The result must still be a whole amount of gas.
+    pub allow_exponent: bool,
+    /// Accept a fraction without the leading zero, e.g. `.5 Tgas`.
+    pub allow_leading_dot: bool,
+    /// Whether only the canonical notation is accepted.
+    pub mode: ParseMode,
+}
+
+impl ParseOptions {
+    /// Returns the default options, which accept exactly what `FromStr` accepts.
+    pub const fn new() -> Self {
+        Self {
+            default_unit: None,
+            allow_underscores: false,
+            allow_thousands_separators: false,
+            allow_exponent: false,
+            allow_leading_dot: false,
+            mode: ParseMode::Lenient,
+        }
+    }
+
+    /// Returns options that accept only the canonical notation, see [`ParseMode::Strict`].
+    pub const fn strict() -> Self {
+        Self {
+            mode: ParseMode::Strict,
+            ..Self::new()
+        }
+    }
+
+    /// Returns options that enable all the optional number notations, but still require a unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, ParseOptions};
+    ///
+    /// let options = ParseOptions::lenient_numbers();
+    /// assert_eq!(NearGas::parse_with("300_000 Ggas", options), Ok(NearGas::from_tgas(300)));
+    /// assert_eq!(NearGas::parse_with("1,000 Tgas", options), Ok(NearGas::from_pgas(1)));
+    /// assert_eq!(NearGas::parse_with("3e14 gas", options), Ok(NearGas::from_tgas(300)));
+    /// assert_eq!(NearGas::parse_with(".5 Tgas", options), Ok(NearGas::from_ggas(500)));
+    /// ```
+    pub const fn lenient_numbers() -> Self {
+        Self {
+            default_unit: None,
+            allow_underscores: true,
+            allow_thousands_separators: true,
+            allow_exponent: true,
+            allow_leading_dot: true,
+            mode: ParseMode::Lenient,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parses `s` into `NearGas`; the `const` implementation of both `FromStr` and [`NearGas::parse_with`].
+pub(crate) const fn parse_near_gas(
+    s: &str,
+    options: ParseOptions,
+) -> Result<NearGas, NearGasError> {
+    if let ParseMode::Strict = options.mode {
+        return parse_canonical(s);
+    }
+    let bytes = s.as_bytes();
+    let (start, end) = trim(bytes);
+    if start == end {
+        return Err(NearGasError::new(NearGasErrorKind::Empty, 0, s.len()));
+    }
+    // The unit starts at the first letter after the last digit, so that stray letters inside
+    // the number are reported as invalid digits rather than as an unknown unit.
+    let mut number_end = end;
+    while number_end > start && !bytes[number_end - 1].is_ascii_digit() {
+        number_end -= 1;
+    }
+    let mut unit_start = number_end;
+    while unit_start < end && !bytes[unit_start].is_ascii_alphabetic() {
+        unit_start += 1;
+    }
+    let unit = if unit_start < end {
+        match GasUnit::parse_name(subslice(bytes, unit_start, end)) {
+            Ok(unit) => unit,
+            Err(err) => return Err(err.offset(unit_start)),
+        }
+    } else {
+        match options.default_unit {
+            Some(unit) => unit,
+            None => return Err(NearGasError::new(NearGasErrorKind::MissingUnit, end, end)),
+        }
+    };
+    let value = subslice(bytes, start, unit_start);
+    let (value_start, value_end) = trim(value);
+    let value_offset = start + value_start;
+    match parse_decimal_number(
+        subslice(value, value_start, value_end),
+        unit.multiplier(),
+        options,
+    ) {
+        Ok(gas) => Ok(NearGas::from_gas(gas)),
+        Err(err) => Err(err.offset(value_offset).with_unit(unit)),
+    }
+}
+
+/// Parses `s` in [`ParseMode::Strict`].
+const fn parse_canonical(s: &str) -> Result<NearGas, NearGasError> {
+    let bytes = s.as_bytes();
+    let (start, end) = trim(bytes);
+    if start == end {
+        return Err(NearGasError::new(NearGasErrorKind::Empty, 0, s.len()));
+    }
+    if start > 0 {
+        return Err(NearGasError::new(NearGasErrorKind::NonCanonical, 0, start));
+    }
+    if end < bytes.len() {
+        return Err(NearGasError::new(
+            NearGasErrorKind::NonCanonical,
+            end,
+            bytes.len(),
+        ));
+    }
+    let mut number_end = end;
+    while number_end > 0 && !bytes[number_end - 1].is_ascii_digit() {
+        number_end -= 1;
+    }
+    let mut unit_start = number_end;
+    while unit_start < end && !bytes[unit_start].is_ascii_alphabetic() {
+        unit_start += 1;
+    }
+    if unit_start == end {
+        return Err(NearGasError::new(NearGasErrorKind::MissingUnit, end, end));
+    }
+    let unit_name = subslice(bytes, unit_start, end);
+    let unit = match GasUnit::parse_name(unit_name) {
+        Ok(unit) => unit,
+        Err(err) => return Err(err.offset(unit_start)),
+    };
+    if !bytes_eq(unit_name, unit.name().as_bytes()) {
+        return Err(
+            NearGasError::new(NearGasErrorKind::NonCanonical, unit_start, end)
+                .with_suggestion(Some(unit)),
+        );
+    }
+
+    let mut value_end = unit_start;
+    while value_end > 0 && bytes[value_end - 1].is_ascii_whitespace() {
+        value_end -= 1;
+    }
+    if unit_start - value_end != 1 || bytes[value_end] != b' ' {
+        return Err(NearGasError::new(
+            NearGasErrorKind::NonCanonical,
+            value_end,
+            unit_start,
+        ));
+    }
+    if value_end > 1 && bytes[0] == b'0' && bytes[1].is_ascii_digit() {
+        return Err(NearGasError::new(NearGasErrorKind::NonCanonical, 0, 1).with_unit(unit));
+    }
+    match parse_decimal_number(
+        subslice(bytes, 0, value_end),
+        unit.multiplier(),
+        ParseOptions::new(),
+    ) {
+        Ok(gas) => Ok(NearGas::from_gas(gas)),
+        Err(err) => Err(err.with_unit(unit)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasUnit, NearGas, NearGasError, NearGasErrorKind, ParseMode, ParseOptions};
+
+    const GAS_BY_DEFAULT: ParseOptions = ParseOptions {
+        default_unit: Some(GasUnit::Gas),
+        ..ParseOptions::new()
+    };
+
+    #[test]
+    fn default_unit() {
+        assert_eq!(
+            NearGas::parse_with(" 300000000000000 ", GAS_BY_DEFAULT),
+            Ok(NearGas::from_tgas(300))
+        );
+        assert_eq!(
+            NearGas::parse_with(
+                "1.5",
+                ParseOptions {
+                    default_unit: Some(GasUnit::Tgas),
+                    ..ParseOptions::new()
+                }
+            ),
+            Ok(NearGas::from_ggas(1_500))
+        );
+        assert_eq!(
+            NearGas::parse_with("1.5", GAS_BY_DEFAULT),
+            Err(
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 2, 3)
+                    .with_unit(GasUnit::Gas)
+            )
+        );
+        assert_eq!(
+            NearGas::parse_with("1x5", GAS_BY_DEFAULT),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2).with_unit(GasUnit::Gas))
+        );
+    }
+
+    #[test]
+    fn unit_is_required_by_default() {
+        assert_eq!(
+            NearGas::parse_with("1000", ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::MissingUnit, 4, 4))
+        );
+    }
+
+    #[test]
+    fn explicit_unit_overrides_default() {
+        assert_eq!(
+            NearGas::parse_with("5 Ggas", GAS_BY_DEFAULT),
+            Ok(NearGas::from_ggas(5))
+        );
+        assert_eq!(
+            NearGas::parse_with("5 UAH", GAS_BY_DEFAULT),
+            Err(NearGasError::new(NearGasErrorKind::UnknownUnit, 2, 5))
+        );
+    }
+
+    #[test]
+    fn underscores() {
+        let options = ParseOptions {
+            allow_underscores: true,
+            ..ParseOptions::new()
+        };
+        assert_eq!(
+            NearGas::parse_with("300_000_000_000_000 gas", options),
+            Ok(NearGas::from_tgas(300))
+        );
+        assert_eq!(
+            NearGas::parse_with("0.000_5 Tgas", options),
+            Ok(NearGas::from_gas(500_000_000))
+        );
+        for (input, span) in [("_1 gas", 0..1), ("1_ gas", 1..2), ("1__0 gas", 1..2)] {
+            assert_eq!(
+                NearGas::parse_with(input, options).unwrap_err().span(),
+                span
+            );
+        }
+        assert_eq!(
+            NearGas::parse_with("1_000 gas", ParseOptions::new())
+                .unwrap_err()
+                .kind(),
+            NearGasErrorKind::InvalidDigit
+        );
+    }
+
+    #[test]
+    fn thousands_separators() {
+        let options = ParseOptions {
+            allow_thousands_separators: true,
+            ..ParseOptions::new()
+        };
+        assert_eq!(
+            NearGas::parse_with("1,000 Tgas", options),
+            Ok(NearGas::from_pgas(1))
+        );
+        assert_eq!(
+            NearGas::parse_with("12,345,678.5 Kgas", options),
+            Ok(NearGas::from_gas(12_345_678_500))
+        );
+        for (input, span) in [
+            ("1,00 Tgas", 1..2),
+            ("1234,567 Tgas", 4..5),
+            ("1,000,0000 Tgas", 5..6),
+            ("0.000,1 Tgas", 5..6),
+            (",100 Tgas", 0..1),
+        ] {
+            let err = NearGas::parse_with(input, options).unwrap_err();
+            assert_eq!(err.kind(), NearGasErrorKind::InvalidDigit, "{}", input);
+            assert_eq!(err.span(), span, "{}", input);
+        }
+    }
+
+    #[test]
+    fn exponent() {
+        let options = ParseOptions {
+            allow_exponent: true,
+            ..ParseOptions::new()
+        };
+        for (input, expected) in [
+            ("3e14 gas", NearGas::from_tgas(300)),
+            ("3E14 gas", NearGas::from_tgas(300)),
+            ("3e+14 gas", NearGas::from_tgas(300)),
+            (
+                "2.5e-3 Tgas",
+                NearGas::from_ggas(2)
+                    .checked_add(NearGas::from_gas(500_000_000))
+                    .unwrap(),
+            ),
+            ("1.5e1 gas", NearGas::from_gas(15)),
+            ("0e99999999999999999999 gas", NearGas::from_gas(0)),
+            ("1.8446744073709551615e19 gas", NearGas::from_gas(u64::MAX)),
+            ("10e-1 gas", NearGas::from_gas(1)),
+        ] {
+            assert_eq!(
+                NearGas::parse_with(input, options),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+        for (input, kind, span) in [
+            ("1.5e0 gas", NearGasErrorKind::TooManyFractionalDigits, 2..3),
+            ("3e-1 gas", NearGasErrorKind::TooManyFractionalDigits, 0..1),
+            ("1e20 gas", NearGasErrorKind::Overflow, 0..4),
+            (
+                "1e99999999999999999999 gas",
+                NearGasErrorKind::Overflow,
+                0..22,
+            ),
+            ("1e+ 5 gas", NearGasErrorKind::InvalidDigit, 3..4),
+            ("1e1.5 gas", NearGasErrorKind::InvalidDigit, 3..4),
+        ] {
+            let err = NearGas::parse_with(input, options).unwrap_err();
+            assert_eq!((err.kind(), err.span()), (kind, span), "{}", input);
+        }
+        assert_eq!(
+            NearGas::parse_with("3e14 gas", ParseOptions::new())
+                .unwrap_err()
+                .span(),
+            1..2
+        );
+    }
+
+    #[test]
+    fn leading_dot() {
+        let options = ParseOptions {
+            allow_leading_dot: true,
+            ..ParseOptions::new()
+        };
+        assert_eq!(
+            NearGas::parse_with(".5 Tgas", options),
+            Ok(NearGas::from_ggas(500))
+        );
+        assert_eq!(
+            NearGas::parse_with(". Tgas", options).unwrap_err().span(),
+            0..1
+        );
+        assert_eq!(
+            NearGas::parse_with(".5 Tgas", ParseOptions::new())
+                .unwrap_err()
+                .span(),
+            0..1
+        );
+    }
+
+    #[test]
+    fn strict_mode_accepts_canonical_notation_only() {
+        let strict = ParseOptions::strict();
+        for (input, expected) in [
+            ("0 gas", NearGas::from_gas(0)),
+            ("1000 gas", NearGas::from_gas(1000)),
+            ("1.5 Kgas", NearGas::from_gas(1_500)),
+            ("2 Mgas", NearGas::from_mgas(2)),
+            ("0.5 Ggas", NearGas::from_mgas(500)),
+            ("300 Tgas", NearGas::from_tgas(300)),
+            ("1.50 Tgas", NearGas::from_ggas(1_500)),
+            ("0.001 Pgas", NearGas::from_tgas(1)),
+        ] {
+            assert_eq!(
+                NearGas::parse_with(input, strict),
+                Ok(expected),
+                "{}",
+                input
+            );
+        }
+
+        for (input, expected) in [
+            ("", NearGasError::new(NearGasErrorKind::Empty, 0, 0)),
+            (
+                " 1 Tgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 0, 1),
+            ),
+            (
+                "1 Tgas\n",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 6, 7),
+            ),
+            (
+                "1Tgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 1, 1),
+            ),
+            (
+                "1  Tgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 1, 3),
+            ),
+            (
+                "1\tTgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 1, 2),
+            ),
+            (
+                "1 tgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 2, 6)
+                    .with_suggestion(Some(GasUnit::Tgas)),
+            ),
+            (
+                "1 teragas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 2, 9)
+                    .with_suggestion(Some(GasUnit::Tgas)),
+            ),
+            (
+                "01 Tgas",
+                NearGasError::new(NearGasErrorKind::NonCanonical, 0, 1).with_unit(GasUnit::Tgas),
+            ),
+            (
+                "1000",
+                NearGasError::new(NearGasErrorKind::MissingUnit, 4, 4),
+            ),
+            (
+                "1_000 gas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2).with_unit(GasUnit::Gas),
+            ),
+            (
+                ".5 Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1).with_unit(GasUnit::Tgas),
+            ),
+            (
+                "1. Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2).with_unit(GasUnit::Tgas),
+            ),
+        ] {
+            assert_eq!(
+                NearGas::parse_with(input, strict),
+                Err(expected),
+                "{:?}",
+                input
+            );
+        }
+
+        // Number options and the default unit only apply in lenient mode.
+        let options = ParseOptions {
+            default_unit: Some(GasUnit::Gas),
+            mode: ParseMode::Strict,
+            ..ParseOptions::lenient_numbers()
+        };
+        assert!(NearGas::parse_with("1000", options).is_err());
+        assert!(NearGas::parse_with("1e3 gas", options).is_err());
+    }
+
+    #[test]
+    fn lenient_mode_accepts_human_input() {
+        let lenient = ParseOptions::default();
+        assert_eq!(lenient.mode, ParseMode::Lenient);
+        for input in [
+            "1.5 Tgas",
+            "1.5Tgas",
+            "1.5 tgas",
+            "1.5 TGAS",
+            "1.5 teragas",
+            "1.5   TeraGas",
+            "1.5\tTgas",
+            "  1.5 Tgas  ",
+            "\u{a0}1.5 Tgas\n",
+            "01.5 Tgas",
+        ] {
+            assert_eq!(
+                NearGas::parse_with(input, lenient),
+                Ok(NearGas::from_ggas(1_500)),
+                "{:?}",
+                input
+            );
+        }
+    }
+}
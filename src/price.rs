@@ -0,0 +1,419 @@
+use crate::{GasUnit, NearGas, NearGasError, NearGasErrorKind};
+
+/// The price of a single unit of gas in yoctoNEAR, as reported by the `gas_price` RPC method.
+///
+/// `Display` renders the price of a teragas, in NEAR if it is at least 1 NEAR and in milliNEAR
+/// otherwise, e.g. `0.1 milliNEAR/Tgas`. The alternate flag (`{:#}`) prints the exact price of a
+/// single gas instead, e.g. `100000000 yoctoNEAR/gas`.
+///
+/// `FromStr` accepts the number of yoctoNEAR per gas as returned by the RPC, e.g. `100000000`, or
+/// an amount of `NEAR`, `milliNEAR` (`mNEAR`) or `yoctoNEAR` per unit of gas, e.g.
+/// `0.1 milliNEAR/Tgas`.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasPrice, NearGas};
+///
+/// let price: GasPrice = "0.1 milliNEAR/Tgas".parse().unwrap();
+/// assert_eq!(price, GasPrice::from_yocto_per_gas(100_000_000));
+/// assert_eq!(price.to_string(), "0.1 milliNEAR/Tgas");
+/// assert_eq!(format!("{:#}", price), "100000000 yoctoNEAR/gas");
+///
+/// // 30 Tgas cost 3 milliNEAR
+/// assert_eq!(NearGas::from_tgas(30).cost_at(price), Some(3 * 10u128.pow(21)));
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct GasPrice {
+    inner: u128,
+}
+
+impl GasPrice {
+    /// Creates a new `GasPrice` from the specified number of yoctoNEAR per gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasPrice;
+    ///
+    /// let price = GasPrice::from_yocto_per_gas(100_000_000);
+    /// assert_eq!(price.as_yocto_per_gas(), 100_000_000);
+    /// ```
+    pub const fn from_yocto_per_gas(inner: u128) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the number of yoctoNEAR per gas.
+    pub const fn as_yocto_per_gas(self) -> u128 {
+        self.inner
+    }
+
+    /// Returns the largest amount of gas that can be bought for `balance` yoctoNEAR at this price.
+    ///
+    /// The result saturates at `u64::MAX` gas, which is also returned for a zero price.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasPrice, NearGas};
+    ///
+    /// let price = GasPrice::from_yocto_per_gas(100_000_000);
+    /// let one_near = 10u128.pow(24);
+    /// assert_eq!(price.gas_affordable(one_near), NearGas::from_pgas(10));
+    /// assert_eq!(price.gas_affordable(99_999_999), NearGas::from_gas(0));
+    /// ```
+    pub const fn gas_affordable(self, balance: u128) -> NearGas {
+        let gas = match balance.checked_div(self.inner) {
+            Some(gas) => gas,
+            None => u128::MAX,
+        };
+        if gas > u64::MAX as u128 {
+            NearGas::from_gas(u64::MAX)
+        } else {
+            NearGas::from_gas(gas as u64)
+        }
+    }
+}
+
+impl From<u128> for GasPrice {
+    fn from(yocto_per_gas: u128) -> Self {
+        Self::from_yocto_per_gas(yocto_per_gas)
+    }
+}
+
+impl From<GasPrice> for u128 {
+    fn from(price: GasPrice) -> Self {
+        price.as_yocto_per_gas()
+    }
+}
+
+/// Splits the price of a teragas into whole and fractional parts of the unit `Display` uses.
+///
+/// Returns the whole part, the fractional part, the number of fractional digits with trailing
+/// zeros removed, and the name of the unit.
+pub(crate) const fn per_tgas_parts(price: GasPrice) -> (u128, u128, usize, &'static str) {
+    // A teragas costs `price * 10^12` yoctoNEAR, and a NEAR is 10^24 yoctoNEAR, so the price in
+    // NEAR has 12 fractional digits, and the price in milliNEAR 9.
+    let (decimals, unit) = if price.inner >= 10u128.pow(12) {
+        (12, "NEAR")
+    } else {
+        (9, "milliNEAR")
+    };
+    let scale = 10u128.pow(decimals);
+    let (whole, mut fraction, mut digits) = (price.inner / scale, price.inner % scale, decimals);
+    while fraction != 0 && fraction % 10 == 0 {
+        fraction /= 10;
+        digits -= 1;
+    }
+    if fraction == 0 {
+        digits = 0;
+    }
+    (whole, fraction, digits as usize, unit)
+}
+
+/// This is the implementation of `FromStr for GasPrice`.
+pub(crate) fn parse_gas_price(s: &str) -> Result<GasPrice, NearGasError> {
+    let start = s.len() - s.trim_start().len();
+    let end = s.trim_end().len();
+    if start >= end {
+        return Err(NearGasError::new(NearGasErrorKind::Empty, 0, s.len()));
+    }
+    let bytes = s.as_bytes();
+
+    let number_start = start;
+    let mut number_end = start;
+    while number_end < end && (bytes[number_end].is_ascii_digit() || bytes[number_end] == b'.') {
+        number_end += 1;
+    }
+    let mut currency_start = number_end;
+    while currency_start < end && bytes[currency_start].is_ascii_whitespace() {
+        currency_start += 1;
+    }
+    let mut currency_end = currency_start;
+    while currency_end < end && bytes[currency_end].is_ascii_alphanumeric() {
+        currency_end += 1;
+    }
+    let mut slash = currency_end;
+    while slash < end && bytes[slash].is_ascii_whitespace() {
+        slash += 1;
+    }
+
+    if number_start == number_end {
+        return Err(match bytes[number_start] {
+            b if b.is_ascii_alphabetic() => {
+                NearGasError::new(NearGasErrorKind::MissingNumber, number_start, number_start)
+            }
+            _ => invalid_digit(s, number_start),
+        });
+    }
+    if currency_start == currency_end {
+        // A plain number of yoctoNEAR per gas, as returned by the RPC.
+        if currency_start < end {
+            return Err(invalid_digit(s, currency_start));
+        }
+        return parse_yocto(s, number_start, number_end, 0).map(GasPrice::from_yocto_per_gas);
+    }
+
+    let decimals: u32 = match &s[currency_start..currency_end] {
+        "NEAR" => 24,
+        "milliNEAR" | "mNEAR" => 21,
+        "yoctoNEAR" => 0,
+        _ => {
+            return Err(NearGasError::new(
+                NearGasErrorKind::UnknownCurrency,
+                currency_start,
+                currency_end,
+            ));
+        }
+    };
+    if slash == end {
+        return Err(NearGasError::new(NearGasErrorKind::MissingUnit, end, end));
+    }
+    if bytes[slash] != b'/' {
+        let len = s[slash..].chars().next().map_or(0, char::len_utf8);
+        return Err(NearGasError::new(
+            NearGasErrorKind::UnexpectedToken,
+            slash,
+            slash + len,
+        ));
+    }
+    let mut unit_start = slash + 1;
+    while unit_start < end && bytes[unit_start].is_ascii_whitespace() {
+        unit_start += 1;
+    }
+    if unit_start == end {
+        return Err(NearGasError::new(NearGasErrorKind::MissingUnit, end, end));
+    }
+    let unit =
+        GasUnit::parse_name(&bytes[unit_start..end]).map_err(|err| err.offset(unit_start))?;
+
+    // The price per gas is the amount scaled by `10^(decimals - unit.decimals())`.
+    match decimals.checked_sub(unit.decimals()) {
+        Some(decimals) => parse_yocto(s, number_start, number_end, decimals),
+        None => {
+            let divisor = 10u128.pow(unit.decimals() - decimals);
+            let yocto = parse_yocto(s, number_start, number_end, 0)?;
+            if yocto % divisor != 0 {
+                return Err(NearGasError::new(
+                    NearGasErrorKind::TooManyFractionalDigits,
+                    number_start,
+                    end,
+                ));
+            }
+            Ok(yocto / divisor)
+        }
+    }
+    .map(GasPrice::from_yocto_per_gas)
+}
+
+/// Parses the decimal number `s[start..end]` scaled by `10^decimals` into `u128`.
+fn parse_yocto(s: &str, start: usize, end: usize, decimals: u32) -> Result<u128, NearGasError> {
+    let overflow = || NearGasError::new(NearGasErrorKind::Overflow, start, end);
+    let (whole, fraction) = match s[start..end].split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (&s[start..end], ""),
+    };
+    // Like the default `ParseOptions`, a dot needs digits on both sides.
+    if whole.is_empty() {
+        return Err(invalid_digit(s, start));
+    }
+    if let Some(dot) = fraction.find('.') {
+        return Err(invalid_digit(s, start + whole.len() + 1 + dot));
+    }
+    if fraction.is_empty() && whole.len() < end - start {
+        return Err(invalid_digit(s, start + whole.len()));
+    }
+    // Digits beyond `decimals` are fractions of a yoctoNEAR and must be zero.
+    let (fraction, excess) = fraction.split_at(fraction.len().min(decimals as usize));
+    if let Some(nonzero) = excess.bytes().position(|digit| digit != b'0') {
+        return Err(NearGasError::new(
+            NearGasErrorKind::TooManyFractionalDigits,
+            end - excess.len() + nonzero,
+            end,
+        ));
+    }
+    let mut value: u128 = 0;
+    for digit in whole.bytes().chain(fraction.bytes()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u128::from(digit - b'0')))
+            .ok_or_else(overflow)?;
+    }
+    value
+        .checked_mul(10u128.pow(decimals - fraction.len() as u32))
+        .ok_or_else(overflow)
+}
+
+fn invalid_digit(s: &str, pos: usize) -> NearGasError {
+    let len = s[pos..].chars().next().map_or(0, char::len_utf8);
+    NearGasError::new(NearGasErrorKind::InvalidDigit, pos, pos + len)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasPrice, GasUnit, NearGas, NearGasError, NearGasErrorKind};
+
+    #[test]
+    fn cost() {
+        let price = GasPrice::from_yocto_per_gas(100_000_000);
+        assert_eq!(
+            NearGas::from_tgas(300).cost_at(price),
+            Some(3 * 10u128.pow(22))
+        );
+        assert_eq!(
+            NearGas::from_gas(u64::MAX).cost_at(GasPrice::from_yocto_per_gas(u128::MAX)),
+            None
+        );
+        assert_eq!(
+            NearGas::from_gas(0).cost_at(GasPrice::from_yocto_per_gas(u128::MAX)),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn gas_affordable_inverts_cost() {
+        let price = GasPrice::from_yocto_per_gas(123_456_789);
+        let gas = NearGas::from_ggas(4_321);
+        let cost = gas.cost_at(price).unwrap();
+        assert_eq!(price.gas_affordable(cost), gas);
+        assert_eq!(
+            price.gas_affordable(cost - 1),
+            gas.saturating_sub(NearGas::from_gas(1))
+        );
+        assert_eq!(
+            GasPrice::default().gas_affordable(1),
+            NearGas::from_gas(u64::MAX)
+        );
+        assert_eq!(
+            GasPrice::from_yocto_per_gas(1).gas_affordable(u128::MAX),
+            NearGas::from_gas(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn display() {
+        for (yocto_per_gas, expected) in [
+            (0, "0 milliNEAR/Tgas"),
+            (1, "0.000000001 milliNEAR/Tgas"),
+            (100_000_000, "0.1 milliNEAR/Tgas"),
+            (1_234_000_000_000, "1.234 NEAR/Tgas"),
+            (999_999_999_999, "999.999999999 milliNEAR/Tgas"),
+        ] {
+            assert_eq!(
+                GasPrice::from_yocto_per_gas(yocto_per_gas).to_string(),
+                expected
+            );
+        }
+        let price = GasPrice::from_yocto_per_gas(100_000_000);
+        assert_eq!(format!("{:>20}", price), "  0.1 milliNEAR/Tgas");
+        assert_eq!(format!("{:#}", price), "100000000 yoctoNEAR/gas");
+    }
+
+    #[test]
+    fn parse() {
+        for (input, expected) in [
+            ("100000000", 100_000_000),
+            (" 100000000 ", 100_000_000),
+            ("0.1 milliNEAR/Tgas", 100_000_000),
+            ("0.1 mNEAR / Tgas", 100_000_000),
+            ("0.0001 NEAR/Tgas", 100_000_000),
+            ("100000000 yoctoNEAR/gas", 100_000_000),
+            ("1 NEAR/Pgas", 1_000_000_000),
+            ("1.0 yoctoNEAR/gas", 1),
+            ("1.0", 1),
+            ("0.10000000 milliNEAR/Tgas", 100_000_000),
+        ] {
+            assert_eq!(
+                input.parse(),
+                Ok(GasPrice::from_yocto_per_gas(expected)),
+                "{}",
+                input
+            );
+        }
+        let max = GasPrice::from_yocto_per_gas(u128::MAX);
+        assert_eq!(max.to_string().parse(), Ok(max));
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (input, expected) in [
+            ("", NearGasError::new(NearGasErrorKind::Empty, 0, 0)),
+            (
+                "NEAR/Tgas",
+                NearGasError::new(NearGasErrorKind::MissingNumber, 0, 0),
+            ),
+            (
+                "-1",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1),
+            ),
+            (
+                "1.5",
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 2, 3),
+            ),
+            (
+                "1.0050 yoctoNEAR/gas",
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 4, 6),
+            ),
+            (
+                "1.2.3 NEAR/gas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 4),
+            ),
+            (
+                "1 NEAR",
+                NearGasError::new(NearGasErrorKind::MissingUnit, 6, 6),
+            ),
+            (
+                "1 NEAR per gas",
+                NearGasError::new(NearGasErrorKind::UnexpectedToken, 7, 8),
+            ),
+            (
+                "1 EUR/gas",
+                NearGasError::new(NearGasErrorKind::UnknownCurrency, 2, 5),
+            ),
+            (
+                "1 NEAR/Tgs",
+                NearGasError::new(NearGasErrorKind::UnknownUnit, 7, 10)
+                    .with_suggestion(Some(GasUnit::Tgas)),
+            ),
+            (
+                "1 yoctoNEAR/Tgas",
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 0, 16),
+            ),
+            (
+                "1 NEAR/ ",
+                NearGasError::new(NearGasErrorKind::MissingUnit, 7, 7),
+            ),
+            (
+                "1 NEAR/\t\t",
+                NearGasError::new(NearGasErrorKind::MissingUnit, 7, 7),
+            ),
+            (".", NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1)),
+            (
+                ". NEAR/Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1),
+            ),
+            (
+                ".5 NEAR/Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1),
+            ),
+            (
+                "100.",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 4),
+            ),
+            (
+                "1. NEAR/Tgas",
+                NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2),
+            ),
+            (
+                "340282366920938463463374607431768211456",
+                NearGasError::new(NearGasErrorKind::Overflow, 0, 39),
+            ),
+        ] {
+            assert_eq!(input.parse::<GasPrice>(), Err(expected), "{}", input);
+        }
+    }
+}
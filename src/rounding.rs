@@ -0,0 +1,69 @@
+/// Rounding strategy used when a gas amount cannot be represented exactly in the requested unit.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, RoundingMode};
+///
+/// let gas = NearGas::from_gas(1_500_000_000_000);
+/// assert_eq!(gas.as_tgas_rounded(RoundingMode::Down), 1);
+/// assert_eq!(gas.as_tgas_rounded(RoundingMode::Up), 2);
+/// assert_eq!(gas.as_tgas_rounded(RoundingMode::Nearest), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round towards zero, discarding the remainder (truncation).
+    #[default]
+    Down,
+    /// Round away from zero if there is any remainder (ceiling).
+    Up,
+    /// Round to the nearest value, ties are rounded away from zero.
+    Nearest,
+}
+
+impl RoundingMode {
+    /// Divides `value` by `divisor`, rounding the quotient according to this mode.
+    ///
+    /// `divisor` must not be zero.
+    pub(crate) const fn div(self, value: u64, divisor: u64) -> u64 {
+        let quotient = value / divisor;
+        let remainder = value % divisor;
+        let round_up = match self {
+            RoundingMode::Down => false,
+            RoundingMode::Up => remainder != 0,
+            RoundingMode::Nearest => remainder >= divisor - divisor / 2,
+        };
+        // `quotient` is at most `u64::MAX / divisor`, so for any `divisor > 1` adding one cannot
+        // overflow, and for `divisor == 1` the remainder is always zero.
+        if round_up { quotient + 1 } else { quotient }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RoundingMode;
+
+    #[test]
+    fn div() {
+        for (value, divisor, down, up, nearest) in [
+            (0, 10, 0, 0, 0),
+            (10, 10, 1, 1, 1),
+            (14, 10, 1, 2, 1),
+            (15, 10, 1, 2, 2),
+            (16, 10, 1, 2, 2),
+            (4, 3, 1, 2, 1),
+            (5, 3, 1, 2, 2),
+            (u64::MAX, 1, u64::MAX, u64::MAX, u64::MAX),
+            (
+                u64::MAX,
+                2,
+                u64::MAX / 2,
+                u64::MAX / 2 + 1,
+                u64::MAX / 2 + 1,
+            ),
+        ] {
+            assert_eq!(RoundingMode::Down.div(value, divisor), down);
+            assert_eq!(RoundingMode::Up.div(value, divisor), up);
+            assert_eq!(RoundingMode::Nearest.div(value, divisor), nearest);
+        }
+    }
+}
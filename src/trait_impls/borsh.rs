@@ -1,8 +1,51 @@
+use borsh::io::{Error, ErrorKind, Read, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{BoundedGas, NearGas};
+
+/// Serialized like `NearGas`; deserializing an amount above the limit fails.
+impl<const MAX: u64> BorshSerialize for BoundedGas<MAX> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        self.get().serialize(writer)
+    }
+}
+
+impl<const MAX: u64> BorshDeserialize for BoundedGas<MAX> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        BoundedGas::new(NearGas::deserialize_reader(reader)?).map_err(|err| {
+            #[cfg(feature = "std")]
+            return Error::new(ErrorKind::InvalidData, err);
+            #[cfg(not(feature = "std"))]
+            Error::new(
+                ErrorKind::InvalidData,
+                alloc::string::ToString::to_string(&err),
+            )
+        })
+    }
+}
+
+/// Described like `NearGas`, regardless of the limit.
+#[cfg(feature = "abi")]
+impl<const MAX: u64> borsh::BorshSchema for BoundedGas<MAX> {
+    fn add_definitions_recursively(
+        definitions: &mut alloc::collections::BTreeMap<
+            borsh::schema::Declaration,
+            borsh::schema::Definition,
+        >,
+    ) {
+        NearGas::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> borsh::schema::Declaration {
+        NearGas::declaration()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use borsh::{BorshDeserialize, to_vec};
 
-    use crate::NearGas;
+    use crate::{GasLimitExceeded, NearGas, NonZeroNearGas, PrepaidGas};
 
     #[test]
     fn borsh() {
@@ -19,4 +62,39 @@ mod test {
         test_borsh_ser(8, [8, 0, 0, 0, 0, 0, 0, 0]);
         test_borsh_ser(0, [0, 0, 0, 0, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn borsh_non_zero() {
+        let gas = NonZeroNearGas::MAX;
+        let ser = to_vec(&gas).unwrap();
+        assert_eq!(ser, to_vec(&gas.get()).unwrap());
+        assert_eq!(NonZeroNearGas::try_from_slice(&ser).unwrap(), gas);
+        assert!(NonZeroNearGas::try_from_slice(&[0; 8]).is_err());
+    }
+
+    #[test]
+    fn borsh_bounded() {
+        let gas = PrepaidGas::MAX;
+        let ser = to_vec(&gas).unwrap();
+        assert_eq!(ser, to_vec(&gas.get()).unwrap());
+        assert_eq!(PrepaidGas::try_from_slice(&ser).unwrap(), gas);
+        let over_limit = to_vec(&NearGas::from_tgas(301)).unwrap();
+        let err = PrepaidGas::try_from_slice(&over_limit).unwrap_err();
+        assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            GasLimitExceeded::new(NearGas::from_tgas(301), NearGas::from_tgas(300)).to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "abi")]
+    fn borsh_schema_bounded() {
+        use borsh::schema::BorshSchemaContainer;
+
+        assert_eq!(
+            BorshSchemaContainer::for_type::<PrepaidGas>(),
+            BorshSchemaContainer::for_type::<NearGas>()
+        );
+    }
 }
@@ -1,4 +1,11 @@
-use crate::{NearGas, NearGasError, ONE_GIGA_GAS};
+use crate::utils::write_padded;
+#[cfg(feature = "alloc")]
+use crate::{AllocationKind, GasBudgetBreakdown, GasBudgetError, GasExceeded};
+use crate::{
+    BoundedGas, DisplayRounding, FloatConversionError, GasLimitExceeded, GasPrice, GasUnit,
+    NearGas, NearGasDelta, NearGasError, NearGasErrorKind, NearGasErrorReport, NonZeroNearGas,
+    ONE_GIGA_GAS,
+};
 
 /// NearGas Display implementation rounds up the gas usage to the relevant precision point.
 /// There are 4 breakpoints:
@@ -6,37 +13,329 @@ use crate::{NearGas, NearGasError, ONE_GIGA_GAS};
 /// 2. <0.001 Tgas
 /// 3. 0.001 - 0.999 Tgas (uses 3 digits after the floating point)
 /// 4. >1 Tgas (uses 1 digit after the floating point)
-impl std::fmt::Display for NearGas {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if *self == NearGas::from_gas(0) {
-            write!(f, "0 Tgas")
-        } else if *self < NearGas::from_ggas(1) {
-            write!(f, "<0.001 Tgas")
-        } else if *self <= NearGas::from_ggas(999) {
-            let gigagas_rounded_up = self.as_gas().saturating_add(ONE_GIGA_GAS - 1) / ONE_GIGA_GAS;
-            write!(f, "0.{:03} Tgas", gigagas_rounded_up)
-        } else {
-            let terragas_rounded_up =
-                self.as_gas().saturating_add(100 * ONE_GIGA_GAS - 1) / ONE_GIGA_GAS / 100;
-            write!(
+///
+/// Standard format flags are honored:
+/// * precision (`{:.3}`) sets the number of digits after the floating point, the value is still rounded up;
+/// * width, fill and alignment (`{:>12}`, `{:*^20}`) pad the whole rendered value, left-aligned by default;
+/// * alternate (`{:#}`) prints the exact number of gas instead, e.g. `1234 gas`.
+///
+/// Use [`NearGas::display`] for full control over the output.
+impl core::fmt::Display for NearGas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let gas = self.as_gas();
+            return write_padded(f, |w| write!(w, "{} {}", gas, GasUnit::Gas));
+        }
+        if let Some(precision) = f.precision() {
+            let display = self.display().precision(precision);
+            return write_padded(f, |w| write!(w, "{}", display));
+        }
+        write_padded(f, |w| {
+            if *self == NearGas::from_gas(0) {
+                write!(w, "0 Tgas")
+            } else if *self < NearGas::from_ggas(1) {
+                write!(w, "<0.001 Tgas")
+            } else if *self <= NearGas::from_ggas(999) {
+                let gigagas_rounded_up =
+                    self.as_gas().saturating_add(ONE_GIGA_GAS - 1) / ONE_GIGA_GAS;
+                write!(w, "0.{:03} Tgas", gigagas_rounded_up)
+            } else {
+                let terragas_rounded_up =
+                    self.as_gas().saturating_add(100 * ONE_GIGA_GAS - 1) / ONE_GIGA_GAS / 100;
+                write!(
+                    w,
+                    "{}.{} Tgas",
+                    terragas_rounded_up / 10,
+                    terragas_rounded_up % 10
+                )
+            }
+        })
+    }
+}
+
+/// Renders the sign followed by the magnitude formatted like `NearGas`, e.g. `+1.2 Tgas`; zero has
+/// no sign. Magnitudes below 1 Tgas drop trailing zeros, e.g. `-0.4 Tgas`, and magnitudes below
+/// 0.001 Tgas carry the sign inside the bound, i.e. `<+0.001 Tgas` or `>-0.001 Tgas`.
+/// The same format flags as for `NearGas` are honored.
+impl core::fmt::Display for NearGasDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = match self.as_gas() {
+            ..0 => "-",
+            0 => "",
+            _ => "+",
+        };
+        let magnitude = self.magnitude();
+        let (alternate, precision) = (f.alternate(), f.precision());
+        write_padded(f, |w| match (alternate, precision) {
+            (true, _) => write!(w, "{}{:#}", sign, magnitude),
+            (false, Some(precision)) => write!(w, "{}{:.*}", sign, precision, magnitude),
+            (false, None) if magnitude == NearGas::from_gas(0) => write!(w, "0 Tgas"),
+            (false, None) if magnitude < NearGas::from_ggas(1) => {
+                let bound = if sign == "-" { ">" } else { "<" };
+                write!(w, "{}{}0.001 Tgas", bound, sign)
+            }
+            (false, None) if magnitude <= NearGas::from_ggas(999) => {
+                let display = magnitude.display().precision(3).trim_trailing_zeros();
+                write!(w, "{}{}", sign, display)
+            }
+            (false, None) => write!(w, "{}{}", sign, magnitude),
+        })
+    }
+}
+
+/// Renders the amount exactly like `NearGas` does, honoring the same format flags.
+impl core::fmt::Display for NonZeroNearGas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+/// Renders the price of a teragas in NEAR or milliNEAR, e.g. `0.1 milliNEAR/Tgas`, without
+/// rounding. Width, fill and alignment are honored like for `NearGas`, and the alternate flag
+/// (`{:#}`) prints the exact price of a single gas, e.g. `100000000 yoctoNEAR/gas`.
+impl core::fmt::Display for GasPrice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let yocto_per_gas = self.as_yocto_per_gas();
+            return write_padded(f, |w| write!(w, "{} yoctoNEAR/gas", yocto_per_gas));
+        }
+        let (whole, fraction, digits, unit) = crate::price::per_tgas_parts(*self);
+        write_padded(f, |w| {
+            if digits == 0 {
+                write!(w, "{} {}/Tgas", whole, unit)
+            } else {
+                write!(w, "{}.{:0digits$} {}/Tgas", whole, fraction, unit)
+            }
+        })
+    }
+}
+
+impl core::fmt::Display for GasUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl core::fmt::Display for NearGasErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl core::fmt::Display for NearGasError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_near_gas_error(self, None, f)
+    }
+}
+
+impl core::fmt::Display for NearGasErrorReport<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid gas amount {:?}: ", self.input)?;
+        fmt_near_gas_error(&self.error, self.input.get(self.error.span()), f)
+    }
+}
+
+fn fmt_near_gas_error(
+    err: &NearGasError,
+    token: Option<&str>,
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    write!(f, "{}", err.kind())?;
+    if let Some(token) = token.filter(|token| !token.is_empty()) {
+        write!(f, " {:?}", token)?;
+    }
+    let span = err.span();
+    if span.is_empty() {
+        write!(f, " at byte {}", span.start)?;
+    } else {
+        write!(f, " at bytes {}..{}", span.start, span.end)?;
+    }
+    match (err.kind(), err.unit()) {
+        (NearGasErrorKind::MissingUnit | NearGasErrorKind::UnknownUnit, _) => {
+            if let Some(suggestion) = err.suggestion() {
+                write!(f, ", did you mean `{}`? ", suggestion)?;
+            } else {
+                write!(f, ". ")?;
+            }
+            write!(f, "Expected one of: ")?;
+            for (i, unit) in GasUnit::ALL.into_iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", unit)?;
+            }
+            Ok(())
+        }
+        (NearGasErrorKind::TooManyFractionalDigits, Some(unit)) => write!(
+            f,
+            ", {} allows at most {} digits after the decimal point",
+            unit,
+            unit.decimals()
+        ),
+        (NearGasErrorKind::NonCanonical, _) => match err.suggestion() {
+            Some(suggestion) => write!(f, ", expected `{}`", suggestion),
+            None => Ok(()),
+        },
+        (NearGasErrorKind::Overflow, Some(unit)) => write!(
+            f,
+            ", the maximum is {}",
+            NearGas::from_gas(u64::MAX)
+                .display()
+                .unit(unit)
+                .precision(0)
+                .rounding(DisplayRounding::Exact)
+        ),
+        _ => Ok(()),
+    }
+}
+
+impl core::fmt::Display for GasLimitExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "gas amount {} exceeds the limit of {}",
+            self.gas().exact(),
+            self.limit().exact()
+        )
+    }
+}
+
+/// Renders the amount exactly like `NearGas` does, honoring the same format flags.
+impl<const MAX: u64> core::fmt::Display for BoundedGas<MAX> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+impl core::fmt::Display for FloatConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FloatConversionError::NaN => write!(f, "Gas amount is NaN"),
+            FloatConversionError::Infinite => write!(f, "Gas amount is infinite"),
+            FloatConversionError::Negative => write!(f, "Gas amount is negative"),
+            FloatConversionError::Overflow => write!(f, "Gas amount exceeds u64::MAX gas"),
+        }
+    }
+}
+
+/// Renders the kind as shown in the table of a [`GasBudgetBreakdown`], e.g. `fixed` or
+/// `weight 2`, honoring the width, fill and alignment flags.
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for AllocationKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_padded(f, |w| match self {
+            AllocationKind::Reserved => write!(w, "reserved"),
+            AllocationKind::Fixed => write!(w, "fixed"),
+            AllocationKind::Weighted(weight) => write!(w, "weight {}", weight.0),
+        })
+    }
+}
+
+/// Renders a table with a row per step, the unallocated gas, if any, and the total, with the
+/// amounts formatted exactly and right-aligned.
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for GasBudgetBreakdown<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use crate::utils::display_width;
+
+        let unallocated = (!self.unallocated.is_zero()).then_some(self.unallocated);
+        let mut name_width = ["step", "total"]
+            .into_iter()
+            .chain(unallocated.map(|_| "unallocated"))
+            .chain(self.allocations.iter().map(|allocation| allocation.name))
+            .map(display_width)
+            .fold(0, usize::max);
+        let mut kind_width = display_width("kind");
+        let mut gas_width = display_width(self.prepaid.exact()).max(display_width("gas"));
+        for allocation in &self.allocations {
+            name_width = name_width.max(display_width(allocation.name));
+            kind_width = kind_width.max(display_width(allocation.kind));
+            gas_width = gas_width.max(display_width(allocation.gas.exact()));
+        }
+
+        writeln!(
+            f,
+            "{:name_width$}  {:kind_width$}  {:>gas_width$}",
+            "step", "kind", "gas"
+        )?;
+        for allocation in &self.allocations {
+            writeln!(
                 f,
-                "{}.{} Tgas",
-                terragas_rounded_up / 10,
-                terragas_rounded_up % 10
-            )
+                "{:name_width$}  {:kind_width$}  {:>gas_width$}",
+                allocation.name,
+                allocation.kind,
+                allocation.gas.exact()
+            )?;
         }
+        if let Some(unallocated) = unallocated {
+            writeln!(
+                f,
+                "{:name_width$}  {:kind_width$}  {:>gas_width$}",
+                "unallocated",
+                "",
+                unallocated.exact()
+            )?;
+        }
+        writeln!(
+            f,
+            "{:name_width$}  {:kind_width$}  {:>gas_width$}",
+            "total",
+            "",
+            self.prepaid.exact()
+        )
     }
 }
 
-impl std::fmt::Display for NearGasError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for GasBudgetError<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            NearGasError::IncorrectNumber(err) => write!(f, "Incorrect number: {:?}", err),
-            NearGasError::IncorrectUnit(err) => write!(f, "Incorrect unit: {}", err),
+            GasBudgetError::PrepaidLimitExceeded(err) => {
+                write!(f, "prepaid gas exceeds the protocol limit: {}", err)
+            }
+            GasBudgetError::Overcommitted {
+                step,
+                required,
+                available,
+                prepaid,
+            } => write!(
+                f,
+                "step `{}` needs {}, but only {} of {} prepaid gas are left",
+                step,
+                required.exact(),
+                available.exact(),
+                prepaid.exact()
+            ),
         }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for GasExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (what, requested, available, total) = match self {
+            GasExceeded::Prepaid {
+                requested,
+                available,
+                prepaid,
+            } => ("prepaid gas exceeded", requested, available, prepaid),
+            GasExceeded::Limit {
+                requested,
+                available,
+                limit,
+            } => ("burnt gas limit exceeded", requested, available, limit),
+        };
+        write!(
+            f,
+            "{}: {} requested, but only {} of {} are left",
+            what,
+            requested.exact(),
+            available.exact(),
+            total.exact()
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::NearGas;
@@ -77,4 +376,88 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_display_format_flags() {
+        let gas = NearGas::from_gas(1_234_567_000_000);
+        assert_eq!(format!("{:.3}", gas), "1.235 Tgas");
+        assert_eq!(format!("{:.0}", gas), "2 Tgas");
+        assert_eq!(format!("{:#}", gas), "1234567000000 gas");
+        assert_eq!(format!("{:>12}", gas), "    1.3 Tgas");
+        assert_eq!(format!("{:12}|", gas), "1.3 Tgas    |");
+        assert_eq!(format!("{:*^12}", gas), "**1.3 Tgas**");
+        assert_eq!(format!("{:>12.2}", gas), "   1.24 Tgas");
+        assert_eq!(format!("{:>#20}", gas), "   1234567000000 gas");
+        assert_eq!(format!("{:>8}", NearGas::from_gas(1)), "<0.001 Tgas");
+        assert_eq!(format!("{:>12}", NearGas::from_gas(0)), "      0 Tgas");
+    }
+
+    #[test]
+    fn test_delta_display() {
+        let one = NearGas::from_tgas(1);
+        for (delta, expected) in [
+            (NearGas::from_ggas(2_150).signed_diff(one), "+1.2 Tgas"),
+            (NearGas::from_ggas(600).signed_diff(one), "-0.4 Tgas"),
+            (NearGas::from_ggas(1_400).signed_diff(one), "+0.4 Tgas"),
+            (NearGas::from_ggas(590).signed_diff(one), "-0.41 Tgas"),
+            (one.signed_diff(one), "0 Tgas"),
+            (
+                NearGas::from_gas(0).signed_diff(NearGas::from_gas(1)),
+                ">-0.001 Tgas",
+            ),
+            (
+                NearGas::from_gas(1).signed_diff(NearGas::from_gas(0)),
+                "<+0.001 Tgas",
+            ),
+        ] {
+            assert_eq!(delta.to_string(), expected);
+        }
+        let delta = NearGas::from_gas(0).signed_diff(NearGas::from_gas(1_234_567_000_000));
+        assert_eq!(format!("{:.3}", delta), "-1.235 Tgas");
+        assert_eq!(format!("{:#}", delta), "-1234567000000 gas");
+        assert_eq!(format!("{:>12}|", delta), "   -1.3 Tgas|");
+    }
+
+    #[test]
+    fn test_error_display() {
+        for (input, expected) in [
+            ("", "invalid gas amount \"\": empty input at byte 0"),
+            (
+                "100",
+                "invalid gas amount \"100\": missing unit at byte 3. \
+                 Expected one of: gas, Kgas, Mgas, Ggas, Tgas, Pgas",
+            ),
+            (
+                "5 TGas.",
+                "invalid gas amount \"5 TGas.\": unknown unit \"TGas.\" at bytes 2..7, \
+                 did you mean `Tgas`? Expected one of: gas, Kgas, Mgas, Ggas, Tgas, Pgas",
+            ),
+            (
+                "1 UAH",
+                "invalid gas amount \"1 UAH\": unknown unit \"UAH\" at bytes 2..5. \
+                 Expected one of: gas, Kgas, Mgas, Ggas, Tgas, Pgas",
+            ),
+            (
+                "Tgas",
+                "invalid gas amount \"Tgas\": missing number before the unit at byte 0",
+            ),
+            (
+                "1x2 Tgas",
+                "invalid gas amount \"1x2 Tgas\": invalid digit \"x\" at bytes 1..2",
+            ),
+            (
+                "0.0000000001 Ggas",
+                "invalid gas amount \"0.0000000001 Ggas\": too many fractional digits \"1\" \
+                 at bytes 11..12, Ggas allows at most 9 digits after the decimal point",
+            ),
+            (
+                "20000000 Tgas",
+                "invalid gas amount \"20000000 Tgas\": number is too large \"20000000\" \
+                 at bytes 0..8, the maximum is 18446744.073709551615 Tgas",
+            ),
+        ] {
+            let err = input.parse::<NearGas>().unwrap_err();
+            assert_eq!(err.with_input(input).to_string(), expected);
+        }
+    }
 }
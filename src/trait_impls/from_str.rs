@@ -1,31 +1,79 @@
-use crate::{NearGas, NearGasError, ONE_GIGA_GAS, ONE_PETA_GAS, ONE_TERA_GAS};
+use crate::{
+    GasPrice, GasUnit, NearGas, NearGasDelta, NearGasError, NearGasErrorKind, NonZeroNearGas,
+    ParseOptions,
+};
 
-impl std::str::FromStr for NearGas {
+impl core::str::FromStr for NearGas {
     type Err = NearGasError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let uppercase_s = s.trim().to_ascii_uppercase();
-        let (value, unit) = uppercase_s.split_at(
-            s.find(|c: char| c.is_ascii_alphabetic())
-                .ok_or_else(|| NearGasError::IncorrectUnit(s.to_owned()))?,
-        );
-        let unit_precision = match unit {
-            "PGAS" | "PETAGAS" => ONE_PETA_GAS,
-            "TGAS" | "TERAGAS" => ONE_TERA_GAS,
-            "GIGAGAS" | "GGAS" => ONE_GIGA_GAS,
-            _ => return Err(NearGasError::IncorrectUnit(s.to_owned())),
+        crate::parse::parse_near_gas(s, ParseOptions::default())
+    }
+}
+
+/// Accepts an optional `+` or `-` sign followed by an amount of gas as accepted by `NearGas`,
+/// e.g. `-0.4 Tgas`.
+impl core::str::FromStr for NearGasDelta {
+    type Err = NearGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let offset = s.len() - s.trim_start().len();
+        let (negative, magnitude) = match s.trim_start().as_bytes().first() {
+            Some(b'-') => (true, &s[offset + 1..]),
+            Some(b'+') => (false, &s[offset + 1..]),
+            _ => (false, s),
         };
-        Ok(NearGas::from_gas(
-            crate::utils::parse_decimal_number(value.trim(), unit_precision)
-                .map_err(NearGasError::IncorrectNumber)?,
-        ))
+        // `NearGas` skips leading whitespace, which must not separate the sign from the number.
+        if let Some(c) = magnitude.chars().next().filter(|c| c.is_whitespace()) {
+            let start = offset + 1;
+            return Err(NearGasError::new(
+                NearGasErrorKind::InvalidDigit,
+                start,
+                start + c.len_utf8(),
+            ));
+        }
+        let gas = magnitude
+            .parse::<NearGas>()
+            .map_err(|err| err.offset(s.len() - magnitude.len()))?;
+        Ok(if negative {
+            NearGasDelta::negative(gas)
+        } else {
+            NearGasDelta::positive(gas)
+        })
+    }
+}
+
+/// Accepts what `NearGas` accepts, except for zero amounts.
+impl core::str::FromStr for NonZeroNearGas {
+    type Err = NearGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let gas: NearGas = s.parse()?;
+        gas.to_non_zero().ok_or_else(|| {
+            let start = s.len() - s.trim_start().len();
+            NearGasError::new(NearGasErrorKind::Zero, start, s.trim_end().len())
+        })
+    }
+}
+
+/// Accepts a number of yoctoNEAR per gas, e.g. `100000000`, or an amount of `NEAR`, `milliNEAR`
+/// or `yoctoNEAR` per unit of gas, e.g. `0.1 milliNEAR/Tgas`.
+impl core::str::FromStr for GasPrice {
+    type Err = NearGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::price::parse_gas_price(s)
+    }
+}
+
+impl core::str::FromStr for GasUnit {
+    type Err = NearGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GasUnit::parse_name(s.as_bytes())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::str::FromStr;
+    use core::str::FromStr;
 
-    use crate::{DecimalNumberParsingError, NearGas, NearGasError};
+    use crate::{GasUnit, NearGas, NearGasDelta, NearGasError, NearGasErrorKind, NonZeroNearGas};
 
     #[test]
     fn doubledot() {
@@ -33,9 +81,7 @@ mod test {
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
         assert_eq!(
             gas,
-            Err(NearGasError::IncorrectNumber(
-                DecimalNumberParsingError::InvalidNumber("1.1.1".to_owned())
-            ))
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 4).with_unit(GasUnit::Tgas))
         )
     }
 
@@ -45,9 +91,7 @@ mod test {
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
         assert_eq!(
             gas,
-            Err(NearGasError::IncorrectNumber(
-                DecimalNumberParsingError::InvalidNumber("1. 0".to_owned())
-            ))
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 2, 3).with_unit(GasUnit::Tgas))
         )
     }
 
@@ -62,14 +106,21 @@ mod test {
     fn incorect_currency() {
         let data = "0 pas";
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(NearGasError::IncorrectUnit(data.to_owned())))
+        assert_eq!(
+            gas,
+            Err(NearGasError::new(NearGasErrorKind::UnknownUnit, 2, 5)
+                .with_suggestion(Some(GasUnit::Gas)))
+        )
     }
 
     #[test]
     fn without_currency() {
         let data = "0";
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(NearGasError::IncorrectUnit("0".to_owned())))
+        assert_eq!(
+            gas,
+            Err(NearGasError::new(NearGasErrorKind::MissingUnit, 1, 1))
+        )
     }
 
     #[test]
@@ -78,9 +129,7 @@ mod test {
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
         assert_eq!(
             gas,
-            Err(NearGasError::IncorrectNumber(
-                DecimalNumberParsingError::InvalidNumber("-1".to_owned())
-            ))
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1).with_unit(GasUnit::Tgas))
         )
     }
 
@@ -89,28 +138,35 @@ mod test {
         let near_gas = NearGas::from_str(".055ggas").unwrap_err();
         assert_eq!(
             near_gas,
-            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
-                ".055".to_string()
-            ))
+            NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1).with_unit(GasUnit::Ggas)
         );
     }
 
     #[test]
     fn test_from_str_without_unit() {
         let near_gas = NearGas::from_str("100").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100".to_string()));
+        assert_eq!(
+            near_gas,
+            NearGasError::new(NearGasErrorKind::MissingUnit, 3, 3)
+        );
     }
 
     #[test]
     fn test_from_str_incorrect_unit() {
         let near_gas = NearGas::from_str("100 UAH").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100 UAH".to_string()));
+        assert_eq!(
+            near_gas,
+            NearGasError::new(NearGasErrorKind::UnknownUnit, 4, 7)
+        );
     }
 
     #[test]
     fn test_from_str_invalid_double_dot() {
         let near_gas = NearGas::from_str("100.55.").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100.55.".to_string()));
+        assert_eq!(
+            near_gas,
+            NearGasError::new(NearGasErrorKind::MissingUnit, 7, 7)
+        );
     }
 
     #[test]
@@ -118,9 +174,8 @@ mod test {
         let near_gas = NearGas::from_str("100.1111122222333 ggas").unwrap_err(); // 13 digits after "."
         assert_eq!(
             near_gas,
-            NearGasError::IncorrectNumber(DecimalNumberParsingError::LongFractional(
-                "1111122222333".to_string()
-            ))
+            NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 13, 17)
+                .with_unit(GasUnit::Ggas)
         );
     }
 
@@ -129,9 +184,7 @@ mod test {
         let near_gas = NearGas::from_str("200123456789123.0 tgas").unwrap_err();
         assert_eq!(
             near_gas,
-            NearGasError::IncorrectNumber(DecimalNumberParsingError::LongWhole(
-                "200123456789123".to_string()
-            ))
+            NearGasError::new(NearGasErrorKind::Overflow, 0, 17).with_unit(GasUnit::Tgas)
         );
     }
 
@@ -140,9 +193,7 @@ mod test {
         let near_gas = NearGas::from_str("-100 ggas").unwrap_err();
         assert_eq!(
             near_gas,
-            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
-                "-100".to_string()
-            ))
+            NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1).with_unit(GasUnit::Ggas)
         );
     }
 
@@ -181,6 +232,65 @@ mod test {
         );
     }
 
+    #[test]
+    fn near_gas_from_str_kilo_and_mega_gas() {
+        assert_eq!(NearGas::from_str("15 kgas"), Ok(NearGas::from_kgas(15)));
+        assert_eq!(
+            NearGas::from_str("1.5 kilogas"),
+            Ok(NearGas::from_gas(1_500))
+        );
+        assert_eq!(
+            NearGas::from_str("2.25 Mgas"),
+            Ok(NearGas::from_kgas(2_250))
+        );
+        assert_eq!(NearGas::from_str("7 MEGAGAS"), Ok(NearGas::from_mgas(7)));
+        assert_eq!(
+            NearGas::from_str("0.0001 kgas"),
+            Err(
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 5, 6)
+                    .with_unit(GasUnit::Kgas)
+            )
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_accepts_every_gas_unit_alias() {
+        // Only `Pgas`, `Tgas` and `Ggas` with their long names were accepted before `GasUnit`.
+        for (input, expected) in [
+            ("3 gas", NearGas::from_gas(3)),
+            ("3 Kgas", NearGas::from_gas(3_000)),
+            ("3 kilogas", NearGas::from_gas(3_000)),
+            ("3 Mgas", NearGas::from_gas(3_000_000)),
+            ("3 megagas", NearGas::from_gas(3_000_000)),
+        ] {
+            assert_eq!(NearGas::from_str(input), Ok(expected), "{}", input);
+        }
+    }
+
+    #[test]
+    fn trailing_zeros_beyond_unit_decimals_are_accepted() {
+        // Zeros below a single gas do not change the amount, so they are no longer rejected.
+        assert_eq!(
+            NearGas::from_str("1.0000000000000 Tgas"),
+            Ok(NearGas::from_tgas(1))
+        );
+        assert_eq!(
+            NearGas::from_str("1.5000 gas"),
+            Err(
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 2, 6)
+                    .with_unit(GasUnit::Gas)
+            )
+        );
+        assert_eq!(
+            NearGas::from_str("1.0000000000001 Tgas"),
+            Err(
+                NearGasError::new(NearGasErrorKind::TooManyFractionalDigits, 14, 15)
+                    .with_unit(GasUnit::Tgas)
+            )
+        );
+        assert_eq!(NearGas::from_str("7.000 gas"), Ok(NearGas::from_gas(7)));
+    }
+
     #[test]
     fn near_gas_from_str_f64_pgas() {
         assert_eq!(
@@ -195,4 +305,143 @@ mod test {
             NearGas::from_gas(1_000_000) // 7 digits
         );
     }
+
+    #[test]
+    fn near_gas_delta_from_str() {
+        let gas = NearGas::from_ggas(400);
+        for (input, expected) in [
+            ("-0.4 Tgas", NearGasDelta::negative(gas)),
+            ("+0.4 Tgas", NearGasDelta::positive(gas)),
+            ("0.4 Tgas", NearGasDelta::positive(gas)),
+            ("  -400ggas ", NearGasDelta::negative(gas)),
+            ("-0 gas", NearGasDelta::default()),
+        ] {
+            assert_eq!(NearGasDelta::from_str(input), Ok(expected), "{}", input);
+        }
+        for delta in [
+            NearGasDelta::MIN,
+            NearGasDelta::MAX,
+            NearGasDelta::negative(NearGas::from_gas(1)),
+        ] {
+            assert_eq!(NearGasDelta::from_str(&format!("{:#}", delta)), Ok(delta));
+        }
+        assert_eq!(
+            NearGasDelta::from_str(" -1x5 Tgas"),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 4).with_unit(GasUnit::Tgas))
+        );
+        assert_eq!(
+            NearGasDelta::from_str("--1 Tgas"),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2).with_unit(GasUnit::Tgas))
+        );
+        for input in ["- 1 Tgas", "+ 1 Tgas", "-\t1 Tgas"] {
+            assert_eq!(
+                NearGasDelta::from_str(input),
+                Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2)),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            NearGasDelta::from_str("  -\u{3000}1 Tgas"),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 6))
+        );
+    }
+
+    #[test]
+    fn non_zero_near_gas_from_str() {
+        assert_eq!(
+            NonZeroNearGas::from_str("30 Tgas").map(NonZeroNearGas::get),
+            Ok(NearGas::from_tgas(30))
+        );
+        assert_eq!(
+            NonZeroNearGas::from_str(" 0.0 Tgas "),
+            Err(NearGasError::new(NearGasErrorKind::Zero, 1, 9))
+        );
+        assert_eq!(
+            NonZeroNearGas::from_str("1 Tgs").unwrap_err().kind(),
+            NearGasErrorKind::UnknownUnit
+        );
+    }
+
+    #[test]
+    fn gas_unit_from_str() {
+        for unit in GasUnit::ALL {
+            for alias in unit.aliases() {
+                assert_eq!(GasUnit::from_str(alias), Ok(unit));
+                assert_eq!(GasUnit::from_str(&alias.to_ascii_uppercase()), Ok(unit));
+            }
+            assert_eq!(GasUnit::from_str(&unit.to_string()), Ok(unit));
+        }
+        assert_eq!(
+            GasUnit::from_str("tgass"),
+            Err(NearGasError::new(NearGasErrorKind::UnknownUnit, 0, 5)
+                .with_suggestion(Some(GasUnit::Tgas)))
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_all_units() {
+        for unit in GasUnit::ALL {
+            assert_eq!(
+                NearGas::from_str(&format!("7 {}", unit)).unwrap(),
+                NearGas::from_unit(7, unit)
+            );
+        }
+        assert_eq!(
+            NearGas::from_str("  1.5 Mgas").unwrap(),
+            NearGas::from_gas(1_500_000)
+        );
+    }
+
+    #[test]
+    fn exact_round_trip_edge_cases() {
+        for gas in [
+            0,
+            1,
+            999,
+            1_000,
+            1_001,
+            10u64.pow(15),
+            u64::MAX - 1,
+            u64::MAX,
+        ] {
+            let gas = NearGas::from_gas(gas);
+            assert_eq!(NearGas::from_str(&gas.exact().to_string()), Ok(gas));
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn exact_round_trip(gas: u64) {
+            let gas = NearGas::from_gas(gas);
+            proptest::prop_assert_eq!(NearGas::from_str(&gas.exact().to_string()), Ok(gas));
+        }
+
+        #[test]
+        fn exact_round_trip_small(gas in 0..10u64.pow(16)) {
+            let gas = NearGas::from_gas(gas);
+            proptest::prop_assert_eq!(NearGas::from_str(&gas.exact().to_string()), Ok(gas));
+        }
+    }
+
+    #[test]
+    fn error_spans_account_for_whitespace() {
+        assert_eq!(
+            NearGas::from_str("   "),
+            Err(NearGasError::new(NearGasErrorKind::Empty, 0, 3))
+        );
+        assert_eq!(
+            NearGas::from_str("  Tgas"),
+            Err(NearGasError::new(NearGasErrorKind::MissingNumber, 2, 2).with_unit(GasUnit::Tgas))
+        );
+        assert_eq!(NearGas::from_str("  1x5 Tgas").unwrap_err().span(), 3..4);
+        assert_eq!(NearGas::from_str(" 12  Tgs ").unwrap_err().span(), 5..8);
+    }
+
+    #[test]
+    fn near_gas_from_str_raw_gas() {
+        assert_eq!(NearGas::from_str("1000 gas"), Ok(NearGas::from_gas(1000)));
+        assert_eq!(NearGas::from_str("1000gas"), Ok(NearGas::from_gas(1000)));
+        assert_eq!(NearGas::from_str("1000 GAS"), Ok(NearGas::from_gas(1000)));
+    }
 }
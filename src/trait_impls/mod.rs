@@ -4,6 +4,7 @@ mod display;
 mod from_str;
 #[cfg(feature = "interactive-clap")]
 mod interactive_clap;
+mod ops;
 #[cfg(any(feature = "schemars-v0_8", feature = "schemars-v1"))]
 mod schemars;
 #[cfg(feature = "serde")]
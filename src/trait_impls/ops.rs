@@ -0,0 +1,304 @@
+//! Operator overloads for `NearGas`, `NearGasDelta` and `NonZeroNearGas`.
+//!
+//! Unlike the primitive integer operators, these always panic on overflow, underflow or division
+//! by zero, regardless of whether the crate is built in debug or release mode. Gas accounting must
+//! never silently wrap; use the `checked_*` or `saturating_*` methods when a panic is not
+//! acceptable.
+
+use core::iter::Sum;
+use core::num::NonZeroU64;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+use crate::{NearGas, NearGasDelta, NonZeroNearGas};
+
+impl Add for NearGas {
+    type Output = NearGas;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn add(self, rhs: NearGas) -> NearGas {
+        self.strict_add(rhs)
+    }
+}
+
+impl AddAssign for NearGas {
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn add_assign(&mut self, rhs: NearGas) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for NearGas {
+    type Output = NearGas;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is greater than `self`.
+    fn sub(self, rhs: NearGas) -> NearGas {
+        self.strict_sub(rhs)
+    }
+}
+
+impl SubAssign for NearGas {
+    /// # Panics
+    ///
+    /// Panics if `rhs` is greater than `self`.
+    fn sub_assign(&mut self, rhs: NearGas) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u64> for NearGas {
+    type Output = NearGas;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn mul(self, rhs: u64) -> NearGas {
+        self.strict_mul(rhs)
+    }
+}
+
+impl Div<u64> for NearGas {
+    type Output = NearGas;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: u64) -> NearGas {
+        self.strict_div(rhs)
+    }
+}
+
+impl Rem<u64> for NearGas {
+    type Output = NearGas;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: u64) -> NearGas {
+        self.strict_rem(rhs)
+    }
+}
+
+/// Dividing one gas amount by another yields the (truncated) ratio between them.
+impl Div<NearGas> for NearGas {
+    type Output = u64;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: NearGas) -> u64 {
+        self.as_gas()
+            .checked_div(rhs.as_gas())
+            .expect("attempt to divide NearGas by zero")
+    }
+}
+
+impl Sum for NearGas {
+    /// # Panics
+    ///
+    /// Panics if the total overflows `u64`.
+    fn sum<I: Iterator<Item = NearGas>>(iter: I) -> NearGas {
+        iter.fold(NearGas::from_gas(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a NearGas> for NearGas {
+    /// # Panics
+    ///
+    /// Panics if the total overflows `u64`.
+    fn sum<I: Iterator<Item = &'a NearGas>>(iter: I) -> NearGas {
+        iter.copied().sum()
+    }
+}
+
+impl Add for NearGasDelta {
+    type Output = NearGasDelta;
+
+    /// # Panics
+    ///
+    /// Panics if the magnitude of the result exceeds `u64::MAX` gas.
+    fn add(self, rhs: NearGasDelta) -> NearGasDelta {
+        self.checked_add(rhs)
+            .expect("attempt to add NearGasDelta with overflow")
+    }
+}
+
+impl Sub for NearGasDelta {
+    type Output = NearGasDelta;
+
+    /// # Panics
+    ///
+    /// Panics if the magnitude of the result exceeds `u64::MAX` gas.
+    fn sub(self, rhs: NearGasDelta) -> NearGasDelta {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract NearGasDelta with overflow")
+    }
+}
+
+/// Negation never overflows, as the range of `NearGasDelta` is symmetric.
+impl Neg for NearGasDelta {
+    type Output = NearGasDelta;
+
+    fn neg(self) -> NearGasDelta {
+        if self.is_negative() {
+            NearGasDelta::positive(self.magnitude())
+        } else {
+            NearGasDelta::negative(self.magnitude())
+        }
+    }
+}
+
+impl Add<NearGas> for NonZeroNearGas {
+    type Output = NonZeroNearGas;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn add(self, rhs: NearGas) -> NonZeroNearGas {
+        self.checked_add(rhs)
+            .expect("attempt to add NearGas with overflow")
+    }
+}
+
+impl AddAssign<NearGas> for NonZeroNearGas {
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn add_assign(&mut self, rhs: NearGas) {
+        *self = *self + rhs;
+    }
+}
+
+impl Mul<NonZeroU64> for NonZeroNearGas {
+    type Output = NonZeroNearGas;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `u64`.
+    fn mul(self, rhs: NonZeroU64) -> NonZeroNearGas {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply NearGas with overflow")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearGas, NearGasDelta, NonZeroNearGas};
+
+    #[test]
+    fn add_sub() {
+        let mut gas = NearGas::from_tgas(5) + NearGas::from_ggas(300);
+        assert_eq!(gas, NearGas::from_ggas(5_300));
+        gas -= NearGas::from_ggas(300);
+        assert_eq!(gas, NearGas::from_tgas(5));
+        gas += NearGas::from_tgas(1);
+        assert_eq!(gas - NearGas::from_tgas(6), NearGas::from_gas(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add NearGas with overflow")]
+    fn add_overflow() {
+        let _ = NearGas::from_gas(u64::MAX) + NearGas::from_gas(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract NearGas with overflow")]
+    fn sub_underflow() {
+        let _ = NearGas::from_gas(1) - NearGas::from_gas(2);
+    }
+
+    #[test]
+    fn mul_div_rem() {
+        let gas = NearGas::from_gas(10);
+        assert_eq!(gas * 3, NearGas::from_gas(30));
+        assert_eq!(gas / 3, NearGas::from_gas(3));
+        assert_eq!(gas % 3, NearGas::from_gas(1));
+        assert_eq!(NearGas::from_tgas(300) / NearGas::from_tgas(7), 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply NearGas with overflow")]
+    fn mul_overflow() {
+        let _ = NearGas::from_gas(u64::MAX) * 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide NearGas by zero")]
+    fn div_by_zero() {
+        let _ = NearGas::from_gas(1) / 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "with a divisor of zero")]
+    fn rem_by_zero() {
+        let _ = NearGas::from_gas(1) % 0;
+    }
+
+    #[test]
+    fn sum() {
+        let gases = [
+            NearGas::from_tgas(1),
+            NearGas::from_ggas(500),
+            NearGas::from_gas(1),
+        ];
+        assert_eq!(
+            gases.iter().sum::<NearGas>(),
+            NearGas::from_gas(1_500_000_000_001)
+        );
+        assert_eq!(
+            gases.into_iter().sum::<NearGas>(),
+            NearGas::from_gas(1_500_000_000_001)
+        );
+        assert_eq!(
+            core::iter::empty::<NearGas>().sum::<NearGas>(),
+            NearGas::from_gas(0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add NearGas with overflow")]
+    fn sum_overflow() {
+        let _: NearGas = [NearGas::from_gas(u64::MAX), NearGas::from_gas(1)]
+            .iter()
+            .sum();
+    }
+
+    #[test]
+    fn delta() {
+        let one = NearGasDelta::positive(NearGas::from_tgas(1));
+        let half = NearGasDelta::negative(NearGas::from_ggas(500));
+        assert_eq!(one + half, NearGasDelta::positive(NearGas::from_ggas(500)));
+        assert_eq!(
+            half - one,
+            NearGasDelta::negative(NearGas::from_ggas(1_500))
+        );
+        assert_eq!(-half, NearGasDelta::positive(NearGas::from_ggas(500)));
+        assert_eq!(-NearGasDelta::MIN, NearGasDelta::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract NearGasDelta with overflow")]
+    fn delta_overflow() {
+        let _ = NearGasDelta::MIN - NearGasDelta::positive(NearGas::from_gas(1));
+    }
+
+    #[test]
+    fn non_zero() {
+        let mut gas = NearGas::from_tgas(1).to_non_zero().unwrap();
+        gas += NearGas::from_ggas(500);
+        assert_eq!(gas.get(), NearGas::from_ggas(1_500));
+        let gas = gas * core::num::NonZeroU64::new(2).unwrap();
+        assert_eq!(gas.get(), NearGas::from_tgas(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add NearGas with overflow")]
+    fn non_zero_overflow() {
+        let _ = NonZeroNearGas::MAX + NearGas::from_gas(1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::NearGas;
+use crate::{GasPrice, NearGas, NonZeroNearGas};
 
 #[cfg(feature = "schemars-v0_8")]
 impl schemars_v0_8::JsonSchema for NearGas {
@@ -32,6 +32,62 @@ impl schemars_v1::JsonSchema for NearGas {
     }
 }
 
+#[cfg(feature = "schemars-v0_8")]
+impl schemars_v0_8::JsonSchema for NonZeroNearGas {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "NonZeroNearGas".to_string()
+    }
+
+    fn json_schema(
+        generator: &mut schemars_v0_8::r#gen::SchemaGenerator,
+    ) -> schemars_v0_8::schema::Schema {
+        <NearGas as schemars_v0_8::JsonSchema>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "schemars-v1")]
+impl schemars_v1::JsonSchema for NonZeroNearGas {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "NonZeroNearGas".to_string().into()
+    }
+
+    fn json_schema(generator: &mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema {
+        <NearGas as schemars_v1::JsonSchema>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "schemars-v0_8")]
+impl schemars_v0_8::JsonSchema for GasPrice {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "GasPrice".to_string()
+    }
+
+    fn json_schema(
+        generator: &mut schemars_v0_8::r#gen::SchemaGenerator,
+    ) -> schemars_v0_8::schema::Schema {
+        <NearGas as schemars_v0_8::JsonSchema>::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "schemars-v1")]
+impl schemars_v1::JsonSchema for GasPrice {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "GasPrice".to_string().into()
+    }
+
+    fn json_schema(generator: &mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema {
+        <NearGas as schemars_v1::JsonSchema>::json_schema(generator)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::NearGas;
@@ -45,6 +101,32 @@ mod test {
         assert_eq!(schema_json, json!({ "title": "NearGas", "type": "string" }));
     }
 
+    #[test]
+    #[cfg(feature = "schemars-v0_8")]
+    fn non_zero_json_schema_json_eq_v0_8() {
+        use crate::NonZeroNearGas;
+
+        let root = schemars_v0_8::schema_for!(NonZeroNearGas);
+        let schema_json = serde_json::to_value(&root.schema).unwrap();
+        assert_eq!(
+            schema_json,
+            json!({ "title": "NonZeroNearGas", "type": "string" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "schemars-v0_8")]
+    fn gas_price_json_schema_json_eq_v0_8() {
+        use crate::GasPrice;
+
+        let root = schemars_v0_8::schema_for!(GasPrice);
+        let schema_json = serde_json::to_value(&root.schema).unwrap();
+        assert_eq!(
+            schema_json,
+            json!({ "title": "GasPrice", "type": "string" })
+        );
+    }
+
     #[test]
     #[cfg(feature = "schemars-v1")]
     fn json_schema_json_eq_v1() {
@@ -55,4 +137,30 @@ mod test {
             json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "title": "NearGas", "type": "string" })
         );
     }
+
+    #[test]
+    #[cfg(feature = "schemars-v1")]
+    fn non_zero_json_schema_json_eq_v1() {
+        use crate::NonZeroNearGas;
+
+        let root = schemars_v1::schema_for!(NonZeroNearGas);
+        let schema_json = serde_json::to_value(&root).unwrap();
+        assert_eq!(
+            schema_json,
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "title": "NonZeroNearGas", "type": "string" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "schemars-v1")]
+    fn gas_price_json_schema_json_eq_v1() {
+        use crate::GasPrice;
+
+        let root = schemars_v1::schema_for!(GasPrice);
+        let schema_json = serde_json::to_value(&root).unwrap();
+        assert_eq!(
+            schema_json,
+            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "title": "GasPrice", "type": "string" })
+        );
+    }
 }
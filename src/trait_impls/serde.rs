@@ -1,26 +1,49 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::NearGas;
+use crate::{BoundedGas, GasPrice, NearGas, NearGasDelta, NonZeroNearGas};
+
+/// Stack buffer for the decimal representation of a number: a `u64` number of gas never takes
+/// more than 20 decimal digits, a `NearGasDelta` one more for the sign, and a `u128` gas price 39.
+struct DigitsBuf {
+    buf: [u8; 39],
+    len: usize,
+}
+
+impl core::fmt::Write for DigitsBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn serialize_digits<S>(value: impl core::fmt::Display, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::Error;
+
+    let mut digits = DigitsBuf {
+        buf: [0u8; 39],
+        len: 0,
+    };
+    core::fmt::Write::write_fmt(&mut digits, format_args!("{}", value))
+        .map_err(|err| Error::custom(format_args!("Failed to serialize: {}", err)))?;
+    let s = core::str::from_utf8(&digits.buf[..digits.len])
+        .map_err(|err| Error::custom(format_args!("Failed to serialize: {}", err)))?;
+    serializer.serialize_str(s)
+}
 
 impl Serialize for NearGas {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        use serde::ser::Error;
-        let mut buf = [0u8; 20];
-        let remainder = {
-            use std::io::Write;
-            let mut w: &mut [u8] = &mut buf;
-            write!(w, "{}", self.inner)
-                .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-            w.len()
-        };
-        let len = buf.len() - remainder;
-
-        let s = std::str::from_utf8(&buf[..len])
-            .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-        serializer.serialize_str(s)
+        serialize_digits(self.inner, serializer)
     }
 }
 
@@ -34,7 +57,7 @@ impl<'de> Deserialize<'de> for NearGas {
         impl serde::de::Visitor<'_> for StringOrNumberVisitor {
             type Value = NearGas;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
                 formatter.write_str("a string or a number")
             }
 
@@ -73,9 +96,167 @@ impl<'de> Deserialize<'de> for NearGas {
     }
 }
 
+/// Serialized as a string with the signed number of gas, e.g. `"-400000000000"`, like `NearGas`.
+impl Serialize for NearGasDelta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_digits(self.as_gas(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NearGasDelta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StringOrNumberVisitor;
+
+        impl serde::de::Visitor<'_> for StringOrNumberVisitor {
+            type Value = NearGasDelta;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a string or a number")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<NearGasDelta, E>
+            where
+                E: serde::de::Error,
+            {
+                let value = value.parse::<i128>().map_err(serde::de::Error::custom)?;
+                NearGasDelta::checked_from_gas(value).ok_or_else(|| {
+                    serde::de::Error::custom("Gas delta magnitude exceeds u64::MAX gas")
+                })
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<NearGasDelta, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(NearGasDelta::positive(NearGas::from_gas(value)))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<NearGasDelta, E>
+            where
+                E: serde::de::Error,
+            {
+                let magnitude = NearGas::from_gas(value.unsigned_abs());
+                Ok(if value < 0 {
+                    NearGasDelta::negative(magnitude)
+                } else {
+                    NearGasDelta::positive(magnitude)
+                })
+            }
+        }
+
+        deserializer.deserialize_any(StringOrNumberVisitor)
+    }
+}
+
+/// Serialized like `NearGas`; deserializing a zero amount fails.
+impl Serialize for NonZeroNearGas {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonZeroNearGas {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        NearGas::deserialize(deserializer)?
+            .to_non_zero()
+            .ok_or_else(|| serde::de::Error::custom("Gas amount must not be zero"))
+    }
+}
+
+/// Serialized like `NearGas`; deserializing an amount above the limit fails.
+impl<const MAX: u64> Serialize for BoundedGas<MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, const MAX: u64> Deserialize<'de> for BoundedGas<MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BoundedGas::new(NearGas::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialized as a string with the number of yoctoNEAR per gas, e.g. `"100000000"`, like the
+/// `gas_price` RPC method returns it.
+impl Serialize for GasPrice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_digits(self.as_yocto_per_gas(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GasPrice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StringOrNumberVisitor;
+
+        impl serde::de::Visitor<'_> for StringOrNumberVisitor {
+            type Value = GasPrice;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a string or a number")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<GasPrice, E>
+            where
+                E: serde::de::Error,
+            {
+                value
+                    .parse::<u128>()
+                    .map(GasPrice::from_yocto_per_gas)
+                    .map_err(serde::de::Error::custom)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<GasPrice, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(GasPrice::from_yocto_per_gas(value.into()))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<GasPrice, E>
+            where
+                E: serde::de::Error,
+            {
+                let Ok(value_u128) = value.try_into() else {
+                    return Err(serde::de::Error::custom(
+                        "Negative value cannot be converted to GasPrice",
+                    ));
+                };
+
+                Ok(GasPrice::from_yocto_per_gas(value_u128))
+            }
+        }
+
+        deserializer.deserialize_any(StringOrNumberVisitor)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::NearGas;
+    use crate::{NearGas, NearGasDelta, NonZeroNearGas};
 
     #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
     struct Wrapper {
@@ -180,4 +361,73 @@ mod test {
             "NearGas should handle u64::MAX when encoded as string"
         );
     }
+
+    #[test]
+    fn delta_json() {
+        for delta in [
+            NearGasDelta::MIN,
+            NearGasDelta::MAX,
+            NearGasDelta::negative(NearGas::from_ggas(400)),
+            NearGasDelta::default(),
+        ] {
+            let ser = serde_json::to_string(&delta).unwrap();
+            assert_eq!(ser, format!("\"{}\"", delta.as_gas()));
+            assert_eq!(serde_json::from_str::<NearGasDelta>(&ser).unwrap(), delta);
+        }
+        assert_eq!(
+            serde_json::from_str::<NearGasDelta>("-100").unwrap(),
+            NearGasDelta::negative(NearGas::from_gas(100))
+        );
+        assert_eq!(
+            serde_json::from_str::<NearGasDelta>("100").unwrap(),
+            NearGasDelta::positive(NearGas::from_gas(100))
+        );
+        assert!(serde_json::from_str::<NearGasDelta>("\"-18446744073709551616\"").is_err());
+        assert!(serde_json::from_str::<NearGasDelta>("\"1 Tgas\"").is_err());
+    }
+
+    #[test]
+    fn non_zero_json() {
+        let gas = NonZeroNearGas::MAX;
+        let ser = serde_json::to_string(&gas).unwrap();
+        assert_eq!(ser, format!("\"{}\"", u64::MAX));
+        assert_eq!(serde_json::from_str::<NonZeroNearGas>(&ser).unwrap(), gas);
+        assert_eq!(
+            serde_json::from_str::<NonZeroNearGas>("1").unwrap(),
+            NonZeroNearGas::MIN
+        );
+        assert!(serde_json::from_str::<NonZeroNearGas>("\"0\"").is_err());
+        assert!(serde_json::from_str::<NonZeroNearGas>("0").is_err());
+    }
+
+    #[test]
+    fn bounded_json() {
+        use crate::PrepaidGas;
+
+        let gas = PrepaidGas::MAX;
+        let ser = serde_json::to_string(&gas).unwrap();
+        assert_eq!(ser, "\"300000000000000\"");
+        assert_eq!(serde_json::from_str::<PrepaidGas>(&ser).unwrap(), gas);
+        let err = serde_json::from_str::<PrepaidGas>("\"300000000000001\"").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "gas amount 300.000000000001 Tgas exceeds the limit of 300 Tgas"
+        );
+    }
+
+    #[test]
+    fn gas_price_json() {
+        use crate::GasPrice;
+
+        let price = GasPrice::from_yocto_per_gas(u128::MAX);
+        let ser = serde_json::to_string(&price).unwrap();
+        assert_eq!(ser, format!("\"{}\"", u128::MAX));
+        assert_eq!(serde_json::from_str::<GasPrice>(&ser).unwrap(), price);
+        assert_eq!(
+            serde_json::from_str::<GasPrice>("100000000").unwrap(),
+            GasPrice::from_yocto_per_gas(100_000_000)
+        );
+        assert!(serde_json::from_str::<GasPrice>("-1").is_err());
+        assert!(serde_json::from_str::<GasPrice>("\"0.1 milliNEAR/Tgas\"").is_err());
+    }
 }
@@ -0,0 +1,280 @@
+use crate::{NearGasError, NearGasErrorKind};
+
+/// A unit of measurement for gas.
+///
+/// All units are SI-prefixed multiples of a single unit of gas.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasUnit, NearGas};
+///
+/// let unit: GasUnit = "teragas".parse().unwrap();
+/// assert_eq!(unit, GasUnit::Tgas);
+/// assert_eq!(unit.to_string(), "Tgas");
+/// assert_eq!(NearGas::from_unit(5, unit), NearGas::from_tgas(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GasUnit {
+    /// A single unit of gas.
+    Gas,
+    /// Kilogas, 10^3 gas.
+    Kgas,
+    /// Megagas, 10^6 gas.
+    Mgas,
+    /// Gigagas, 10^9 gas.
+    Ggas,
+    /// Teragas, 10^12 gas.
+    Tgas,
+    /// Petagas, 10^15 gas.
+    Pgas,
+}
+
+impl GasUnit {
+    /// All units, ordered from the smallest to the largest.
+    pub const ALL: [GasUnit; 6] = [
+        GasUnit::Gas,
+        GasUnit::Kgas,
+        GasUnit::Mgas,
+        GasUnit::Ggas,
+        GasUnit::Tgas,
+        GasUnit::Pgas,
+    ];
+
+    /// Returns the amount of gas in one of this unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Gas.multiplier(), 1);
+    /// assert_eq!(GasUnit::Tgas.multiplier(), 1_000_000_000_000);
+    /// ```
+    pub const fn multiplier(self) -> u64 {
+        match self {
+            GasUnit::Gas => 1,
+            GasUnit::Kgas => 10u64.pow(3),
+            GasUnit::Mgas => 10u64.pow(6),
+            GasUnit::Ggas => 10u64.pow(9),
+            GasUnit::Tgas => 10u64.pow(12),
+            GasUnit::Pgas => 10u64.pow(15),
+        }
+    }
+
+    /// Returns the number of decimal digits a value in this unit may have after the decimal point
+    /// while still being a whole number of gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Gas.decimals(), 0);
+    /// assert_eq!(GasUnit::Tgas.decimals(), 12);
+    /// ```
+    pub const fn decimals(self) -> u32 {
+        match self {
+            GasUnit::Gas => 0,
+            GasUnit::Kgas => 3,
+            GasUnit::Mgas => 6,
+            GasUnit::Ggas => 9,
+            GasUnit::Tgas => 12,
+            GasUnit::Pgas => 15,
+        }
+    }
+
+    /// Returns the canonical short name of the unit, as used by `Display`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Tgas.name(), "Tgas");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
+            GasUnit::Gas => "gas",
+            GasUnit::Kgas => "Kgas",
+            GasUnit::Mgas => "Mgas",
+            GasUnit::Ggas => "Ggas",
+            GasUnit::Tgas => "Tgas",
+            GasUnit::Pgas => "Pgas",
+        }
+    }
+
+    /// Returns the spelled out name of the unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Tgas.long_name(), "teragas");
+    /// ```
+    pub const fn long_name(self) -> &'static str {
+        match self {
+            GasUnit::Gas => "gas",
+            GasUnit::Kgas => "kilogas",
+            GasUnit::Mgas => "megagas",
+            GasUnit::Ggas => "gigagas",
+            GasUnit::Tgas => "teragas",
+            GasUnit::Pgas => "petagas",
+        }
+    }
+
+    /// Returns all names accepted by `FromStr` for this unit. Matching is case-insensitive.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Tgas.aliases(), &["Tgas", "teragas"]);
+    /// ```
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            GasUnit::Gas => &["gas"],
+            GasUnit::Kgas => &["Kgas", "kilogas"],
+            GasUnit::Mgas => &["Mgas", "megagas"],
+            GasUnit::Ggas => &["Ggas", "gigagas"],
+            GasUnit::Tgas => &["Tgas", "teragas"],
+            GasUnit::Pgas => &["Pgas", "petagas"],
+        }
+    }
+
+    /// Matches `s` against the aliases of all units, ignoring case.
+    ///
+    /// This is the `const` implementation of `FromStr for GasUnit`; spans of the returned errors
+    /// are relative to `s`.
+    pub(crate) const fn parse_name(s: &[u8]) -> Result<GasUnit, NearGasError> {
+        if s.is_empty() {
+            return Err(NearGasError::new(NearGasErrorKind::MissingUnit, 0, 0));
+        }
+        let mut i = 0;
+        while i < GasUnit::ALL.len() {
+            let unit = GasUnit::ALL[i];
+            let aliases = unit.aliases();
+            let mut j = 0;
+            while j < aliases.len() {
+                if eq_ignore_ascii_case(s, aliases[j].as_bytes()) {
+                    return Ok(unit);
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        Err(NearGasError::new(NearGasErrorKind::UnknownUnit, 0, s.len())
+            .with_suggestion(GasUnit::suggest(s)))
+    }
+
+    /// Returns the unit whose name most closely resembles the unknown unit `s`, if any is close enough.
+    ///
+    /// Trailing punctuation and case are ignored. One typo (insertion, deletion or substitution) is
+    /// tolerated for short names like `Tgas`, and two for long names like `teragas`.
+    pub(crate) const fn suggest(s: &[u8]) -> Option<GasUnit> {
+        let mut end = s.len();
+        while end > 0 && s[end - 1].is_ascii_punctuation() {
+            end -= 1;
+        }
+        let s = s.split_at(end).0;
+        let mut best: Option<(usize, GasUnit)> = None;
+        let mut i = 0;
+        while i < GasUnit::ALL.len() {
+            let unit = GasUnit::ALL[i];
+            let aliases = unit.aliases();
+            let mut j = 0;
+            while j < aliases.len() {
+                let alias = aliases[j].as_bytes();
+                j += 1;
+                let Some(distance) = edit_distance(s, alias) else {
+                    continue;
+                };
+                let max_distance = if alias.len() < 5 { 1 } else { 2 };
+                let closer = match best {
+                    Some((best_distance, _)) => distance < best_distance,
+                    None => true,
+                };
+                if distance <= max_distance && closer {
+                    best = Some((distance, unit));
+                }
+            }
+            i += 1;
+        }
+        match best {
+            Some((_, unit)) => Some(unit),
+            None => None,
+        }
+    }
+}
+
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Case-insensitive Levenshtein distance, computed without allocation.
+/// Returns `None` if `a` is too long to be a misspelled unit name.
+const fn edit_distance(a: &[u8], b: &[u8]) -> Option<usize> {
+    const MAX_LEN: usize = 16;
+    if a.len() > MAX_LEN || b.len() > MAX_LEN {
+        return None;
+    }
+    let mut previous = [0usize; MAX_LEN + 1];
+    let mut current = [0usize; MAX_LEN + 1];
+    let mut j = 0;
+    while j <= b.len() {
+        previous[j] = j;
+        j += 1;
+    }
+    let mut i = 1;
+    while i <= a.len() {
+        current[0] = i;
+        let mut j = 1;
+        while j <= b.len() {
+            let substitution = !a[i - 1].eq_ignore_ascii_case(&b[j - 1]) as usize;
+            let mut distance = previous[j - 1] + substitution;
+            if previous[j] + 1 < distance {
+                distance = previous[j] + 1;
+            }
+            if current[j - 1] + 1 < distance {
+                distance = current[j - 1] + 1;
+            }
+            current[j] = distance;
+            j += 1;
+        }
+        core::mem::swap(&mut previous, &mut current);
+        i += 1;
+    }
+    Some(previous[b.len()])
+}
+
+#[cfg(test)]
+mod test {
+    use super::GasUnit;
+
+    #[test]
+    fn multiplier_matches_decimals() {
+        for unit in GasUnit::ALL {
+            assert_eq!(unit.multiplier(), 10u64.pow(unit.decimals()));
+        }
+    }
+
+    #[test]
+    fn names_are_aliases() {
+        for unit in GasUnit::ALL {
+            assert!(unit.aliases().contains(&unit.name()));
+            assert!(unit.aliases().contains(&unit.long_name()));
+        }
+    }
+
+    #[test]
+    fn suggest() {
+        assert_eq!(GasUnit::suggest(b"Tgs"), Some(GasUnit::Tgas));
+        assert_eq!(GasUnit::suggest(b"TGas."), Some(GasUnit::Tgas));
+        assert_eq!(GasUnit::suggest(b"terragas"), Some(GasUnit::Tgas));
+        assert_eq!(GasUnit::suggest(b"gigagaz"), Some(GasUnit::Ggas));
+        assert_eq!(GasUnit::suggest(b"gass"), Some(GasUnit::Gas));
+        assert_eq!(GasUnit::suggest(b"UAH"), None);
+        assert_eq!(GasUnit::suggest(b"averyveryverylongunitname"), None);
+    }
+}
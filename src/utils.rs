@@ -1,95 +1,359 @@
-/// Parsing decimal numbers from `&str` type in `u64`.
-/// Function also takes a value of metric prefix in u64 type.
+use crate::{NearGasError, NearGasErrorKind, ParseOptions};
+
+/// Unwraps a `Result` or returns its error, like `?` does outside of `const fn`.
+macro_rules! const_try {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+/// Parsing decimal numbers from a byte string in `u64`.
+/// Function also takes a value of metric prefix in u64 type, which must be a power of 10.
 /// `parse_str` use the `u64` type, and have the same max and min values.
 ///
-/// If the fractional part is longer than several zeros in the prefix, it will return the error `DecimalNumberParsingError::LongFractional`.
+/// Digit separators, exponent notation and fractions without a leading zero are accepted only
+/// when enabled in `options`.
+///
+/// If the number has non-zero digits below a single unit of gas, it will return the error
+/// `NearGasErrorKind::TooManyFractionalDigits`.
+///
+/// If the string slice has invalid chars, it will return the error `NearGasErrorKind::InvalidDigit`.
 ///
-/// If the string slice has invalid chars, it will return the error `DecimalNumberParsingError::InvalidNumber`.
+/// If the resulting value is more than the `u64` maximum value, it will return the error `NearGasErrorKind::Overflow`.
 ///
-/// If the whole part of the number has a value more than the `u64` maximum value, it will return the error `DecimalNumberParsingError::LongWhole`.
-pub(crate) fn parse_decimal_number(
-    s: &str,
+/// Spans of the returned errors are relative to `s`. The function is `const`, so it can be used to
+/// parse literals at compile time.
+pub(crate) const fn parse_decimal_number(
+    s: &[u8],
     pref_const: u64,
-) -> Result<u64, DecimalNumberParsingError> {
-    let (int, fract) = if let Some((whole, fractional)) = s.trim().split_once('.') {
-        let int: u64 = whole
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let mut fract: u64 = fractional
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let len = u32::try_from(fractional.len())
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        fract = fract
-            .checked_mul(
-                pref_const
-                    .checked_div(10u64.checked_pow(len).ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?)
-                    .filter(|n| *n != 0u64)
-                    .ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?,
-            )
-            .ok_or_else(|| DecimalNumberParsingError::LongFractional(fractional.to_owned()))?;
-        (int, fract)
-    } else {
-        let int: u64 = s
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        (int, 0)
+    options: ParseOptions,
+) -> Result<u64, NearGasError> {
+    if s.is_empty() {
+        return Err(NearGasError::new(NearGasErrorKind::MissingNumber, 0, 0));
+    }
+
+    let mut mantissa_end = s.len();
+    let mut exponent = 0;
+    if options.allow_exponent {
+        if let Some(e) = find_byte(s, 0, s.len(), b'e', b'E') {
+            mantissa_end = e;
+            exponent = const_try!(parse_exponent(s, e));
+        }
+    }
+    let dot = find_byte(s, 0, mantissa_end, b'.', b'.');
+    let whole_end = match dot {
+        Some(dot) => dot,
+        None => mantissa_end,
     };
-    let result = fract
-        .checked_add(
-            int.checked_mul(pref_const)
-                .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?,
-        )
-        .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?;
-    Ok(result)
+    if whole_end == 0 && !(options.allow_leading_dot && dot.is_some()) {
+        return Err(invalid_digit(s, 0));
+    }
+    if let Some(dot) = dot {
+        if dot + 1 == mantissa_end {
+            return Err(invalid_digit(s, whole_end));
+        }
+    }
+    const_try!(validate_digits(
+        s,
+        0,
+        whole_end,
+        options,
+        options.allow_thousands_separators
+    ));
+    if let Some(dot) = dot {
+        const_try!(validate_digits(s, dot + 1, mantissa_end, options, false));
+    }
+
+    let mut decimals: i64 = 0;
+    let mut pref = pref_const;
+    while pref >= 10 && pref % 10 == 0 {
+        pref /= 10;
+        decimals += 1;
+    }
+
+    // Every digit is assigned the power of 10 it contributes in gas; digits with a negative
+    // power are fractions of a single gas and must be zero.
+    let mut whole_digits: i64 = 0;
+    let mut i = 0;
+    while i < whole_end {
+        if s[i].is_ascii_digit() {
+            whole_digits += 1;
+        }
+        i += 1;
+    }
+    let mut power = whole_digits - 1 + decimals + exponent;
+    let mut value: u64 = 0;
+    let mut last_power = None;
+    let mut overflowed = false;
+    let mut excess_start = None;
+    let mut i = 0;
+    while i < mantissa_end {
+        let b = s[i];
+        i += 1;
+        if !b.is_ascii_digit() {
+            continue;
+        }
+        let digit = (b - b'0') as u64;
+        if power >= 0 {
+            match value.checked_mul(10) {
+                Some(tens) => match tens.checked_add(digit) {
+                    Some(new_value) => value = new_value,
+                    None => overflowed = true,
+                },
+                None => overflowed = true,
+            }
+            last_power = Some(power);
+        } else if digit != 0 && excess_start.is_none() {
+            excess_start = Some(i - 1);
+        }
+        power -= 1;
+    }
+
+    if let Some(excess_start) = excess_start {
+        return Err(NearGasError::new(
+            NearGasErrorKind::TooManyFractionalDigits,
+            excess_start,
+            mantissa_end,
+        ));
+    }
+    let overflow = NearGasError::new(NearGasErrorKind::Overflow, 0, s.len());
+    if overflowed {
+        return Err(overflow);
+    }
+    match last_power {
+        Some(last_power) if last_power > 0 && value != 0 => {
+            if last_power > u32::MAX as i64 {
+                return Err(overflow);
+            }
+            match 10u64.checked_pow(last_power as u32) {
+                Some(scale) => match value.checked_mul(scale) {
+                    Some(value) => Ok(value),
+                    None => Err(overflow),
+                },
+                None => Err(overflow),
+            }
+        }
+        _ => Ok(value),
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum DecimalNumberParsingError {
-    InvalidNumber(String),
-    LongWhole(String),
-    LongFractional(String),
+/// Parses the exponent following the `e` at byte `e` of `s`, with an optional sign.
+/// The result is clamped to a range which is far out of reach of any `u64` value.
+const fn parse_exponent(s: &[u8], e: usize) -> Result<i64, NearGasError> {
+    let sign = if e + 1 < s.len() { s[e + 1] } else { 0 };
+    let negative = sign == b'-';
+    let digits_start = match sign {
+        b'-' | b'+' => e + 2,
+        _ => e + 1,
+    };
+    if digits_start >= s.len() {
+        return Err(invalid_digit(s, e));
+    }
+    let mut exponent: i64 = 0;
+    let mut j = digits_start;
+    while j < s.len() {
+        if !s[j].is_ascii_digit() {
+            return Err(invalid_digit(s, j));
+        }
+        exponent = exponent * 10 + (s[j] - b'0') as i64;
+        if exponent > 1_000_000 {
+            exponent = 1_000_000;
+        }
+        j += 1;
+    }
+    Ok(if negative { -exponent } else { exponent })
 }
 
-impl std::error::Error for DecimalNumberParsingError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+/// Checks that `s[start..end]` consists of digits and, if enabled, separators between digits.
+/// Thousands separators must split the digits into groups of three.
+const fn validate_digits(
+    s: &[u8],
+    start: usize,
+    end: usize,
+    options: ParseOptions,
+    allow_thousands_separators: bool,
+) -> Result<(), NearGasError> {
+    let mut group_digits = 0;
+    let mut last_comma = None;
+    let mut i = start;
+    while i < end {
+        let between_digits =
+            i > start && s[i - 1].is_ascii_digit() && i + 1 < end && s[i + 1].is_ascii_digit();
+        match s[i] {
+            b'0'..=b'9' => group_digits += 1,
+            b'_' if options.allow_underscores && between_digits => {}
+            b',' if allow_thousands_separators && between_digits => {
+                match last_comma {
+                    None if group_digits <= 3 => {}
+                    Some(_) if group_digits == 3 => {}
+                    None => return Err(invalid_digit(s, i)),
+                    Some(last_comma) => return Err(invalid_digit(s, last_comma)),
+                }
+                group_digits = 0;
+                last_comma = Some(i);
+            }
+            _ => return Err(invalid_digit(s, i)),
+        }
+        i += 1;
     }
+    match last_comma {
+        Some(last_comma) if group_digits != 3 => Err(invalid_digit(s, last_comma)),
+        _ => Ok(()),
+    }
+}
 
-    fn description(&self) -> &str {
-        "description() is deprecated; use Display"
+/// Returns the position of the first `a` or `b` within `s[start..end]`.
+const fn find_byte(s: &[u8], start: usize, end: usize, a: u8, b: u8) -> Option<usize> {
+    let mut i = start;
+    while i < end {
+        if s[i] == a || s[i] == b {
+            return Some(i);
+        }
+        i += 1;
     }
+    None
+}
+
+/// Returns an `InvalidDigit` error spanning the whole (possibly multibyte) character at byte `i` of `s`.
+const fn invalid_digit(s: &[u8], i: usize) -> NearGasError {
+    let len = if i < s.len() {
+        match s[i] {
+            0xF0.. => 4,
+            0xE0.. => 3,
+            0xC0.. => 2,
+            _ => 1,
+        }
+    } else {
+        1
+    };
+    NearGasError::new(NearGasErrorKind::InvalidDigit, i, i + len)
+}
 
-    fn cause(&self) -> Option<&dyn std::error::Error> {
-        self.source()
+/// Returns whether `a` and `b` are equal, usable in `const fn`.
+pub(crate) const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
     }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
 
-impl std::fmt::Display for DecimalNumberParsingError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            DecimalNumberParsingError::InvalidNumber(s) => {
-                write!(f, "Invalid number: {}", s)
-            }
-            DecimalNumberParsingError::LongWhole(s) => {
-                write!(f, "Long whole part: {}", s)
-            }
-            DecimalNumberParsingError::LongFractional(s) => {
-                write!(f, "Long fractional part: {}", s)
-            }
+/// Returns `s[start..end]`, usable in `const fn`.
+pub(crate) const fn subslice(s: &[u8], start: usize, end: usize) -> &[u8] {
+    s.split_at(end).0.split_at(start).1
+}
+
+/// Returns the range of `s` without leading and trailing whitespace, like `str::trim` does.
+pub(crate) const fn trim(s: &[u8]) -> (usize, usize) {
+    let (mut start, mut end) = (0, s.len());
+    loop {
+        let len = leading_whitespace_len(subslice(s, start, end));
+        if len == 0 {
+            break;
         }
+        start += len;
+    }
+    loop {
+        let len = trailing_whitespace_len(subslice(s, start, end));
+        if len == 0 {
+            break;
+        }
+        end -= len;
+    }
+    (start, end)
+}
+
+/// Returns the length of the whitespace character `s` starts with, or zero.
+/// Whitespace is defined as by `char::is_whitespace`.
+const fn leading_whitespace_len(s: &[u8]) -> usize {
+    match s {
+        [b'\t' | b'\n' | 0x0B | 0x0C | b'\r' | b' ', ..] => 1,
+        [0xC2, 0x85 | 0xA0, ..] => 2,
+        [0xE1, 0x9A, 0x80, ..]
+        | [0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF, ..]
+        | [0xE2, 0x81, 0x9F, ..]
+        | [0xE3, 0x80, 0x80, ..] => 3,
+        _ => 0,
+    }
+}
+
+/// Returns the length of the whitespace character `s` ends with, or zero.
+/// Whitespace is defined as by `char::is_whitespace`.
+const fn trailing_whitespace_len(s: &[u8]) -> usize {
+    match s {
+        [.., b'\t' | b'\n' | 0x0B | 0x0C | b'\r' | b' '] => 1,
+        [.., 0xC2, 0x85 | 0xA0] => 2,
+        [.., 0xE1, 0x9A, 0x80]
+        | [.., 0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF]
+        | [.., 0xE2, 0x81, 0x9F]
+        | [.., 0xE3, 0x80, 0x80] => 3,
+        _ => 0,
+    }
+}
+
+/// Counts the characters written to it.
+struct CharCounter(usize);
+
+impl core::fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Returns the number of characters `value` renders to, e.g. to size a column of a table.
+#[cfg(feature = "alloc")]
+pub(crate) fn display_width(value: impl core::fmt::Display) -> usize {
+    let mut counter = CharCounter(0);
+    // Counting never fails; a failing `Display` impl is reported when the value is written.
+    let _ = core::fmt::Write::write_fmt(&mut counter, format_args!("{}", value));
+    counter.0
+}
+
+/// Writes the output of `render` to `f`, honoring the width, fill and alignment flags of the formatter.
+///
+/// The output is rendered twice, first to measure its length, so padding does not require a buffer.
+/// Text is left-aligned by default, same as for `str`.
+pub(crate) fn write_padded(
+    f: &mut core::fmt::Formatter<'_>,
+    render: impl Fn(&mut dyn core::fmt::Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return render(f);
+    };
+    let mut counter = CharCounter(0);
+    render(&mut counter)?;
+    let padding = width.saturating_sub(counter.0);
+    let (before, after) = match f.align() {
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(core::fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        core::fmt::Write::write_char(f, fill)?;
+    }
+    render(f)?;
+    for _ in 0..after {
+        core::fmt::Write::write_char(f, fill)?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST: [(u64, &str, u64); 6] = [
+    const TEST: [(u64, &str, u64); 8] = [
         (129_380_000_001_u64, "129.380000001", 10u64.pow(9)),
         (
             12_938_000_000_100_000_000_u64,
@@ -104,12 +368,16 @@ mod tests {
             10u64.pow(12),
         ),
         (129_380_000_001_u64, "00.129380000001", 10u64.pow(12)),
+        (1_500_u64, "1.5000", 10u64.pow(3)),
+        (7_u64, "7", 1),
     ];
 
     #[test]
     fn parse_test() {
         for (expected_value, str_value, precision) in TEST {
-            let parsed_value = parse_decimal_number(str_value, precision).unwrap();
+            let parsed_value =
+                parse_decimal_number(str_value.as_bytes(), precision, ParseOptions::default())
+                    .unwrap();
             assert_eq!(parsed_value, expected_value)
         }
     }
@@ -119,8 +387,12 @@ mod tests {
         let data = "1.23456";
         let prefix = 10000u64;
         assert_eq!(
-            parse_decimal_number(data, prefix),
-            Err(DecimalNumberParsingError::LongFractional(23456.to_string()))
+            parse_decimal_number(data.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(
+                NearGasErrorKind::TooManyFractionalDigits,
+                6,
+                7
+            ))
         );
     }
 
@@ -129,10 +401,8 @@ mod tests {
         let num = "1h4.7859";
         let prefix: u64 = 10000;
         assert_eq!(
-            parse_decimal_number(num, prefix),
-            Err(DecimalNumberParsingError::InvalidNumber(
-                "1h4.7859".to_owned()
-            ))
+            parse_decimal_number(num.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2))
         );
     }
     #[test]
@@ -140,10 +410,32 @@ mod tests {
         let num = "14.785h9";
         let prefix: u64 = 10000;
         assert_eq!(
-            parse_decimal_number(num, prefix),
-            Err(DecimalNumberParsingError::InvalidNumber(
-                "14.785h9".to_owned()
-            ))
+            parse_decimal_number(num.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 6, 7))
+        );
+    }
+
+    #[test]
+    fn invalidnumber_multibyte() {
+        assert_eq!(
+            parse_decimal_number("1.2€".as_bytes(), 1000, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 6))
+        );
+    }
+
+    #[test]
+    fn invalidnumber_dots() {
+        assert_eq!(
+            parse_decimal_number(".5".as_bytes(), 1000, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 0, 1))
+        );
+        assert_eq!(
+            parse_decimal_number("5.".as_bytes(), 1000, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 1, 2))
+        );
+        assert_eq!(
+            parse_decimal_number("1.1.1".as_bytes(), 1000, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::InvalidDigit, 3, 4))
         );
     }
 
@@ -153,9 +445,11 @@ mod tests {
         let data = "1.".to_string() + max_data.to_string().as_str();
         let prefix = 10u64.pow(17);
         assert_eq!(
-            parse_decimal_number(data.as_str(), prefix),
-            Err(DecimalNumberParsingError::LongFractional(
-                max_data.to_string()
+            parse_decimal_number(data.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(
+                NearGasErrorKind::TooManyFractionalDigits,
+                19,
+                20
             ))
         );
     }
@@ -166,29 +460,47 @@ mod tests {
         let prefix = 10u64.pow(12);
         let s = data.to_string() + "." + "1";
         assert_eq!(
-            parse_decimal_number(s.as_str(), prefix),
-            Err(DecimalNumberParsingError::LongWhole(data.to_string()))
+            parse_decimal_number(s.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(NearGasErrorKind::Overflow, 0, 20))
         );
     }
 
     #[test]
     fn parse_u64_errortest() {
         let test_data = u64::MAX.to_string();
-        let gas = parse_decimal_number(&test_data, 10u64.pow(9));
+        let gas = parse_decimal_number(test_data.as_bytes(), 10u64.pow(9), ParseOptions::default());
+        assert_eq!(
+            gas,
+            Err(NearGasError::new(NearGasErrorKind::Overflow, 0, 20))
+        );
+        let test_data = "1".repeat(30);
+        let gas = parse_decimal_number(test_data.as_bytes(), 1, ParseOptions::default());
         assert_eq!(
             gas,
-            Err(DecimalNumberParsingError::LongWhole(u64::MAX.to_string()))
+            Err(NearGasError::new(NearGasErrorKind::Overflow, 0, 30))
         );
     }
 
+    #[test]
+    fn trim_matches_str_trim() {
+        for c in (0..=0x3000).filter_map(char::from_u32) {
+            for s in [format!("{c}1{c}"), format!("{c}{c}"), format!("1{c}{c}")] {
+                let (start, end) = trim(s.as_bytes());
+                assert_eq!(&s[start..end], s.trim(), "{:?}", c);
+            }
+        }
+    }
+
     #[test]
     fn test() {
         let data = "1.000000000000000000000000000000000000001";
         let prefix = 100u64;
         assert_eq!(
-            parse_decimal_number(data, prefix),
-            Err(DecimalNumberParsingError::LongFractional(
-                "000000000000000000000000000000000000001".to_string()
+            parse_decimal_number(data.as_bytes(), prefix, ParseOptions::default()),
+            Err(NearGasError::new(
+                NearGasErrorKind::TooManyFractionalDigits,
+                40,
+                41
             ))
         );
     }
@@ -0,0 +1,100 @@
+/// The weight of a function call action for distributing the gas left unused by a receipt, as in
+/// `promise_batch_action_function_call_weight`.
+///
+/// An action with weight 0 receives none of the unused gas. The default weight is 1, matching
+/// `near-sdk`.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasWeight, NearGas};
+///
+/// let weights = [GasWeight(1), GasWeight(2)];
+/// let mut gas = [NearGas::from_gas(0); 2];
+/// NearGas::from_tgas(30).distribute_into(&weights, &mut gas);
+/// assert_eq!(gas, [NearGas::from_tgas(10), NearGas::from_tgas(20)]);
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshDeserialize, borsh::BorshSerialize)
+)]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+#[repr(transparent)]
+pub struct GasWeight(pub u64);
+
+impl Default for GasWeight {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl From<u64> for GasWeight {
+    fn from(weight: u64) -> Self {
+        Self(weight)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasWeight, NearGas};
+
+    fn distribute(gas: u64, weights: &[u64]) -> Vec<u64> {
+        let weights: Vec<GasWeight> = weights.iter().copied().map(GasWeight).collect();
+        let mut out = vec![NearGas::from_gas(u64::MAX); weights.len()];
+        NearGas::from_gas(gas).distribute_into(&weights, &mut out);
+        out.into_iter().map(NearGas::as_gas).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn distribute_allocates_output() {
+        assert_eq!(
+            NearGas::from_tgas(10).distribute(&[GasWeight(1), GasWeight(0), GasWeight(3)]),
+            [
+                NearGas::from_ggas(2_500),
+                NearGas::from_gas(0),
+                NearGas::from_ggas(7_500)
+            ]
+        );
+    }
+
+    #[test]
+    fn remainder_goes_to_last_weighted_action() {
+        assert_eq!(distribute(10, &[1, 1, 1]), [3, 3, 4]);
+        assert_eq!(distribute(10, &[1, 1, 0]), [5, 5, 0]);
+        assert_eq!(distribute(11, &[2, 0, 1, 0]), [6, 0, 5, 0]);
+        assert_eq!(distribute(5, &[3, 7]), [0, 5]);
+        assert_eq!(distribute(100, &[u64::MAX, u64::MAX]), [0, 100]);
+        assert_eq!(distribute(u64::MAX, &[u64::MAX, 1]), [0, u64::MAX]);
+        assert_eq!(
+            distribute(u64::MAX, &[1, 1]),
+            [u64::MAX / 2, u64::MAX / 2 + 1]
+        );
+    }
+
+    #[test]
+    fn unweighted_gas_is_not_distributed() {
+        assert_eq!(distribute(10, &[]), [0u64; 0]);
+        assert_eq!(distribute(10, &[0, 0]), [0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`out` must have the same length as `weights`")]
+    fn distribute_into_checks_length() {
+        NearGas::from_gas(1).distribute_into(&[GasWeight(1)], &mut []);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn distributes_all_gas(gas: u64, weights: Vec<u64>) {
+            let distributed = distribute(gas, &weights);
+            let total: u128 = distributed.iter().map(|&gas| u128::from(gas)).sum();
+            if weights.iter().any(|&weight| weight > 0) {
+                proptest::prop_assert_eq!(total, u128::from(gas));
+            } else {
+                proptest::prop_assert_eq!(total, 0);
+            }
+        }
+    }
+}